@@ -675,6 +675,7 @@ fn main() -> anyhow::Result<()> {
                 )],
                 commands: &mut [&mut buf],
                 signal: &mut [present_sem],
+                ..Default::default()
             }],
             fence.take().unwrap(),
         )?;
@@ -284,6 +284,7 @@ fn main() -> vk::Result<()> {
                 )],
                 commands: &mut [cmd_buf.as_mut().unwrap()],
                 signal: &mut [present_sem],
+                ..Default::default()
             }],
             fence.take().unwrap(),
         )?;
@@ -0,0 +1,444 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::{Read, Write};
+use std::ops::Range;
+use std::sync::Mutex;
+
+use crate::buffer::{Buffer, BufferWithoutMemory};
+use crate::device::Device;
+use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::image::{Image, ImageWithoutMemory};
+use crate::memory::{AllocateOptions, Dedicated, DeviceMemory, MappedMemory};
+use crate::types::*;
+
+/// The default size of the memory blocks resources are allocated from.
+const DEFAULT_BLOCK_SIZE: u64 = 64 << 20;
+
+/// How a resource's memory will be accessed, which determines the memory type
+/// it is allocated from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MemoryUsage {
+    /// Only accessed by the device. Prefers device-local memory.
+    GpuOnly,
+    /// Written by the host and read by the device, for example staging buffers
+    /// and per-frame uniforms. Requires host-visible, host-coherent memory.
+    Upload,
+    /// Written by the device and read by the host. Requires host-visible,
+    /// host-coherent memory, and prefers host-cached memory.
+    Readback,
+}
+
+/// A general-purpose memory allocator.
+///
+/// Memory is allocated from blocks of device memory, one pool of blocks per
+/// memory type, to avoid running into
+/// [`max_memory_allocation_count`](PhysicalDeviceLimits::max_memory_allocation_count).
+/// Resources larger than half a block get a dedicated allocation. Each
+/// resource's memory is returned to its pool when the resource is dropped, and
+/// blocks which are no longer used are freed. Host-visible blocks are mapped,
+/// so that [`write_buffer`](Self::write_buffer()) and
+/// [`read_buffer`](Self::read_buffer()) can access them. This includes
+/// [`GpuOnly`](MemoryUsage::GpuOnly) resources whose memory type is
+/// host-visible, as on devices with unified memory.
+pub struct Allocator {
+    device: Arc<Device>,
+    /// The block size of each memory type.
+    block_sizes: Vec<u64>,
+    memory_types: Vec<MemoryPropertyFlags>,
+    pools: Vec<Arc<Mutex<Pool>>>,
+}
+
+struct Pool {
+    blocks: Vec<PoolBlock>,
+}
+
+struct PoolBlock {
+    block: Arc<Block>,
+    /// Sorted, non-adjacent free ranges.
+    free: Vec<Range<u64>>,
+    dedicated: bool,
+}
+
+struct Block {
+    memory: Mutex<BlockMemory>,
+    size: u64,
+}
+
+enum BlockMemory {
+    Unmapped(DeviceMemory),
+    Mapped(MappedMemory),
+}
+
+/// A range of a memory block, which is returned to the pool when dropped.
+pub(crate) struct Allocation {
+    pool: Arc<Mutex<Pool>>,
+    block: Arc<Block>,
+    offset: u64,
+    size: u64,
+}
+
+impl Allocator {
+    /// Create an allocator with the default block size of 64MiB, or for each
+    /// memory type an eighth of the size of its memory heap, whichever is
+    /// smaller.
+    pub fn new(device: &Arc<Device>) -> Self {
+        Self::with_block_size(device, DEFAULT_BLOCK_SIZE)
+    }
+    /// Create an allocator which allocates memory in blocks of `block_size`
+    /// bytes, or for each memory type an eighth of the size of its memory
+    /// heap, whichever is smaller.
+    pub fn with_block_size(device: &Arc<Device>, block_size: u64) -> Self {
+        let props = device.physical_device().memory_properties();
+        let heaps = props.memory_heaps.as_slice();
+        let memory_types =
+            props.memory_types.iter().map(|ty| ty.property_flags).collect();
+        let block_sizes = props
+            .memory_types
+            .iter()
+            .map(|ty| {
+                let heap = heaps.get(ty.heap_index as usize);
+                block_size.min(heap.map_or(0, |h| h.size) / 8).max(1)
+            })
+            .collect();
+        let pools = (0..props.memory_types.len())
+            .map(|_| Arc::new(Mutex::new(Pool { blocks: vec![] })));
+        Self {
+            device: device.clone(),
+            block_sizes,
+            memory_types,
+            pools: pools.collect(),
+        }
+    }
+
+    /// Returns the associated device.
+    pub fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+
+    /// Returns the index of the best memory type for `usage` out of the ones
+    /// in `memory_type_bits`, or [`None`] if there are none suitable.
+    pub fn memory_type(
+        &self, memory_type_bits: u32, usage: MemoryUsage,
+    ) -> Option<u32> {
+        let host = MemoryPropertyFlags::HOST_VISIBLE
+            | MemoryPropertyFlags::HOST_COHERENT;
+        let (required, preferred, unwanted) = match usage {
+            MemoryUsage::GpuOnly => (
+                MemoryPropertyFlags::empty(),
+                MemoryPropertyFlags::DEVICE_LOCAL,
+                MemoryPropertyFlags::HOST_VISIBLE,
+            ),
+            MemoryUsage::Upload => (
+                host,
+                MemoryPropertyFlags::empty(),
+                MemoryPropertyFlags::HOST_CACHED,
+            ),
+            MemoryUsage::Readback => (
+                host,
+                MemoryPropertyFlags::HOST_CACHED,
+                MemoryPropertyFlags::empty(),
+            ),
+        };
+        let unusable = MemoryPropertyFlags::PROTECTED
+            | MemoryPropertyFlags::LAZILY_ALLOCATED;
+        let score = |flags: MemoryPropertyFlags| {
+            2 * (flags & preferred).bits().count_ones() as i32
+                - (flags & unwanted).bits().count_ones() as i32
+        };
+        self.memory_types
+            .iter()
+            .enumerate()
+            .filter(|&(i, &flags)| {
+                memory_type_bits & (1 << i) != 0
+                    && flags.contains(required)
+                    && !flags.intersects(unusable)
+            })
+            .rev() // max_by_key returns the last maximum
+            .max_by_key(|&(_, &flags)| score(flags))
+            .map(|(i, _)| i as u32)
+    }
+
+    /// Create a buffer and bind it to memory suitable for `usage`. Returns
+    /// [`Error::InvalidArgument`] if there is no suitable memory type. Note
+    /// that uniform, storage, vertex, and index buffers can only use
+    /// host-visible memory if robust buffer access is enabled.
+    #[doc = crate::man_link!(vkCreateBuffer)]
+    pub fn create_buffer(
+        &self, info: &BufferCreateInfo<'_>, usage: MemoryUsage,
+    ) -> Result<Arc<Buffer>> {
+        let buffer = BufferWithoutMemory::new(&self.device, info)?;
+        let dedicated = buffer
+            .dedicated_requirements()
+            .dedicated()
+            .then(|| Dedicated::Buffer(buffer.handle()));
+        let allocation =
+            self.allocate(buffer.memory_requirements(), usage, dedicated)?;
+        let block = allocation.block.clone();
+        let memory = block.memory.lock().unwrap();
+        let offset = allocation.offset;
+        Ok(buffer.bind_allocation(memory.memory(), offset, allocation)?)
+    }
+
+    /// Create an image and bind it to memory suitable for `usage`. Returns
+    /// [`Error::InvalidArgument`] if there is no suitable memory type.
+    #[doc = crate::man_link!(vkCreateImage)]
+    pub fn create_image(
+        &self, info: &ImageCreateInfo<'_>, usage: MemoryUsage,
+    ) -> Result<Arc<Image>> {
+        let image = ImageWithoutMemory::new(&self.device, info)?;
+        let dedicated = image
+            .dedicated_requirements()
+            .dedicated()
+            .then(|| Dedicated::Image(image.handle()));
+        let allocation =
+            self.allocate(image.memory_requirements(), usage, dedicated)?;
+        let block = allocation.block.clone();
+        let memory = block.memory.lock().unwrap();
+        let offset = allocation.offset;
+        Ok(image.bind_allocation(memory.memory(), offset, allocation)?)
+    }
+
+    /// Copy `data` into `buffer` at `offset`. Returns
+    /// [`Error::InvalidArgument`] if the buffer was not created by an
+    /// allocator in host-visible memory, and [`Error::OutOfBounds`] if the
+    /// range is out of bounds.
+    pub fn write_buffer(
+        &self, buffer: &Buffer, offset: u64, data: &[u8],
+    ) -> Result<()> {
+        let allocation = buffer.allocation().ok_or(Error::InvalidArgument)?;
+        if !buffer.bounds_check(offset, data.len() as u64) {
+            return Err(Error::OutOfBounds);
+        }
+        let mut memory = allocation.block.memory.lock().unwrap();
+        match &mut *memory {
+            BlockMemory::Mapped(mapped) => {
+                let at = (allocation.offset + offset) as usize;
+                mapped.write_at(at).write_all(data).unwrap();
+                Ok(())
+            }
+            BlockMemory::Unmapped(_) => Err(Error::InvalidArgument),
+        }
+    }
+
+    /// Copy data out of `buffer` at `offset` into `data`. Returns
+    /// [`Error::InvalidArgument`] if the buffer was not created by an
+    /// allocator in host-visible memory, and [`Error::OutOfBounds`] if the
+    /// range is out of bounds.
+    pub fn read_buffer(
+        &self, buffer: &Buffer, offset: u64, data: &mut [u8],
+    ) -> Result<()> {
+        let allocation = buffer.allocation().ok_or(Error::InvalidArgument)?;
+        if !buffer.bounds_check(offset, data.len() as u64) {
+            return Err(Error::OutOfBounds);
+        }
+        let memory = allocation.block.memory.lock().unwrap();
+        match &*memory {
+            BlockMemory::Mapped(mapped) => {
+                let at = (allocation.offset + offset) as usize;
+                mapped.read_at(at).read_exact(data).unwrap();
+                Ok(())
+            }
+            BlockMemory::Unmapped(_) => Err(Error::InvalidArgument),
+        }
+    }
+
+    /// Suballocate from a block, or allocate a block for the resource if it is
+    /// large or prefers a dedicated allocation.
+    fn allocate(
+        &self, req: MemoryRequirements, usage: MemoryUsage,
+        dedicated: Option<Dedicated<'_>>,
+    ) -> Result<Allocation> {
+        let memory_type_index = self
+            .memory_type(req.memory_type_bits, usage)
+            .ok_or(Error::InvalidArgument)?;
+        // Aligning everything to the granularity means linear and non-linear
+        // resources never share a page.
+        let granularity = self.device.limits().buffer_image_granularity;
+        let align = req.alignment.max(granularity);
+        let size = align_up(req.size, granularity);
+        let pool = &self.pools[memory_type_index as usize];
+        let block_size = self.block_sizes[memory_type_index as usize];
+        let mut guard = pool.lock().unwrap();
+
+        if dedicated.is_some() || size > block_size / 2 {
+            // Dedicated allocations must have exactly the required size.
+            let block_size = if dedicated.is_some() { req.size } else { size };
+            let block =
+                self.new_block(memory_type_index, block_size, dedicated)?;
+            guard.blocks.push(PoolBlock {
+                block: block.clone(),
+                free: vec![],
+                dedicated: true,
+            });
+            return Ok(Allocation {
+                pool: pool.clone(),
+                block,
+                offset: 0,
+                size,
+            });
+        }
+
+        for pool_block in guard.blocks.iter_mut().filter(|b| !b.dedicated) {
+            if let Some(offset) = pool_block.take(size, align) {
+                let block = pool_block.block.clone();
+                return Ok(Allocation {
+                    pool: pool.clone(),
+                    block,
+                    offset,
+                    size,
+                });
+            }
+        }
+        let block = self.new_block(memory_type_index, block_size, None)?;
+        #[allow(clippy::single_range_in_vec_init)]
+        let free = vec![0..block_size];
+        let mut pool_block =
+            PoolBlock { block: block.clone(), free, dedicated: false };
+        let offset = pool_block.take(size, align).unwrap();
+        guard.blocks.push(pool_block);
+        Ok(Allocation { pool: pool.clone(), block, offset, size })
+    }
+
+    fn new_block(
+        &self, memory_type_index: u32, size: u64,
+        dedicated: Option<Dedicated<'_>>,
+    ) -> Result<Arc<Block>> {
+        let memory = DeviceMemory::allocate(
+            &self.device,
+            size,
+            memory_type_index,
+            AllocateOptions { dedicated, ..Default::default() },
+        )?;
+        let flags = self.memory_types[memory_type_index as usize];
+        let memory = if flags.contains(MemoryPropertyFlags::HOST_VISIBLE) {
+            BlockMemory::Mapped(memory.map(0, size as usize)?)
+        } else {
+            BlockMemory::Unmapped(memory)
+        };
+        Ok(Arc::new(Block { memory: Mutex::new(memory), size }))
+    }
+}
+
+impl BlockMemory {
+    fn memory(&self) -> &DeviceMemory {
+        match self {
+            Self::Unmapped(memory) => memory,
+            Self::Mapped(mapped) => mapped.memory(),
+        }
+    }
+}
+
+impl PoolBlock {
+    /// First-fit allocation from the free list.
+    fn take(&mut self, size: u64, align: u64) -> Option<u64> {
+        let (i, start) = self.free.iter().enumerate().find_map(|(i, r)| {
+            let start = align_up(r.start, align);
+            (start.checked_add(size)? <= r.end).then_some((i, start))
+        })?;
+        let range = self.free[i].clone();
+        let mut rest = vec![];
+        if range.start < start {
+            rest.push(range.start..start);
+        }
+        if start + size < range.end {
+            rest.push(start + size..range.end);
+        }
+        self.free.splice(i..=i, rest);
+        Some(start)
+    }
+
+    fn give_back(&mut self, offset: u64, size: u64) {
+        let mut range = offset..offset + size;
+        let i = self.free.partition_point(|r| r.end <= offset);
+        let mut remove = i..i;
+        if i > 0 && self.free[i - 1].end == range.start {
+            range.start = self.free[i - 1].start;
+            remove.start -= 1;
+        }
+        if i < self.free.len() && self.free[i].start == range.end {
+            range.end = self.free[i].end;
+            remove.end += 1;
+        }
+        self.free.splice(remove, [range]);
+    }
+
+    fn is_unused(&self) -> bool {
+        self.dedicated || self.free.first() == Some(&(0..self.block.size))
+    }
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        let mut pool = self.pool.lock().unwrap();
+        let index = pool
+            .blocks
+            .iter()
+            .position(|b| Arc::ptr_eq(&b.block, &self.block))
+            .unwrap();
+        pool.blocks[index].give_back(self.offset, self.size);
+        // Keep one block around to avoid reallocating it repeatedly
+        let regular_blocks = pool.blocks.iter().filter(|b| !b.dedicated);
+        if pool.blocks[index].is_unused()
+            && (pool.blocks[index].dedicated || regular_blocks.count() > 1)
+        {
+            pool.blocks.swap_remove(index);
+        }
+    }
+}
+
+impl std::fmt::Debug for Allocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Allocation")
+            .field("offset", &self.offset)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+fn align_up(value: u64, align: u64) -> u64 {
+    (value + align - 1) & !(align - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn suballocate() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let alloc = vk::Allocator::with_block_size(&dev, 1 << 16);
+        let info = vk::BufferCreateInfo {
+            size: 256,
+            usage: vk::BufferUsageFlags::TRANSFER_SRC
+                | vk::BufferUsageFlags::TRANSFER_DST,
+            ..Default::default()
+        };
+        let upload = alloc.create_buffer(&info, vk::MemoryUsage::Upload)?;
+        let readback = alloc.create_buffer(&info, vk::MemoryUsage::Readback)?;
+        alloc.write_buffer(&upload, 16, &[1, 2, 3, 4])?;
+        let mut data = [0; 4];
+        alloc.read_buffer(&upload, 16, &mut data)?;
+        assert_eq!(data, [1, 2, 3, 4]);
+        assert_eq!(
+            alloc.write_buffer(&readback, 254, &[1, 2, 3, 4]),
+            Err(vk::Error::OutOfBounds)
+        );
+        let large = vk::BufferCreateInfo {
+            size: 1 << 16,
+            usage: info.usage,
+            ..Default::default()
+        };
+        let dedicated = alloc.create_buffer(&large, vk::MemoryUsage::Upload)?;
+        drop(upload);
+        drop(dedicated);
+        let _again = alloc.create_buffer(&info, vk::MemoryUsage::Upload)?;
+        Ok(())
+    }
+}
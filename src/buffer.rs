@@ -6,9 +6,10 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::allocator::Allocation;
 use crate::enums::*;
 use crate::error::{Error, ErrorAndSelf, Result, ResultAndSelf};
-use crate::memory::{DeviceMemory, MemoryLifetime};
+use crate::memory::{AllocateOptions, Dedicated, DeviceMemory, MemoryLifetime};
 use crate::subobject::Subobject;
 use crate::types::*;
 use crate::vk::Device;
@@ -20,6 +21,7 @@ pub struct BufferWithoutMemory {
     handle: Handle<VkBuffer>,
     len: u64,
     usage: BufferUsageFlags,
+    external_handle_types: ExternalMemoryHandleTypeFlags,
     device: Arc<Device>,
 }
 
@@ -30,18 +32,48 @@ pub struct BufferWithoutMemory {
 pub struct Buffer {
     inner: BufferWithoutMemory,
     _memory: Subobject<MemoryLifetime>,
+    allocation: Option<Allocation>,
 }
 
 impl BufferWithoutMemory {
     #[doc = crate::man_link!(vkCreateBuffer)]
     pub fn new(
         device: &Arc<Device>, info: &BufferCreateInfo<'_>,
+    ) -> Result<Self> {
+        Self::create(device, info, None)
+    }
+
+    /// Create a buffer which can be bound to external memory of any of
+    /// `handle_types`. Requires Vulkan 1.1 or VK_KHR_external_memory.
+    #[doc = crate::man_link!(VkExternalMemoryBufferCreateInfo)]
+    pub fn new_external(
+        device: &Arc<Device>, info: &BufferCreateInfo<'_>,
+        handle_types: ExternalMemoryHandleTypeFlags,
+    ) -> Result<Self> {
+        let external = ExternalMemoryBufferCreateInfo {
+            handle_types,
+            ..Default::default()
+        };
+        Self::create(device, info, Some(&external))
+    }
+
+    fn create(
+        device: &Arc<Device>, info: &BufferCreateInfo<'_>,
+        external: Option<&ExternalMemoryBufferCreateInfo>,
     ) -> Result<Self> {
         let mut handle = None;
         unsafe {
             (device.fun.create_buffer)(
                 device.handle(),
-                info,
+                &BufferCreateInfo {
+                    stype: Default::default(),
+                    next: external,
+                    flags: info.flags,
+                    size: info.size,
+                    usage: info.usage,
+                    sharing_mode: info.sharing_mode,
+                    queue_family_indices: info.queue_family_indices,
+                },
                 None,
                 &mut handle,
             )?;
@@ -50,6 +82,8 @@ impl BufferWithoutMemory {
             handle: handle.unwrap(),
             len: info.size,
             usage: info.usage,
+            external_handle_types: external
+                .map_or(Default::default(), |e| e.handle_types),
             device: device.clone(),
         })
     }
@@ -62,15 +96,12 @@ impl Buffer {
     pub fn new(
         buffer: BufferWithoutMemory, memory: &DeviceMemory, offset: u64,
     ) -> ResultAndSelf<Arc<Self>, BufferWithoutMemory> {
-        assert_eq!(memory.device(), &buffer.device);
-        if !memory.check(offset, buffer.memory_requirements()) {
-            return Err(ErrorAndSelf(Error::InvalidArgument, buffer));
-        }
-        Self::bind_buffer_impl(buffer, memory, offset)
+        buffer.bind_memory(memory, offset)
     }
 
     fn bind_buffer_impl(
         mut inner: BufferWithoutMemory, memory: &DeviceMemory, offset: u64,
+        allocation: Option<Allocation>,
     ) -> ResultAndSelf<Arc<Buffer>, BufferWithoutMemory> {
         if let Err(err) = unsafe {
             (memory.device().fun.bind_buffer_memory)(
@@ -82,7 +113,7 @@ impl Buffer {
         } {
             return Err(ErrorAndSelf(err.into(), inner));
         }
-        Ok(Arc::new(Buffer { inner, _memory: memory.resource() }))
+        Ok(Arc::new(Buffer { inner, _memory: memory.resource(), allocation }))
     }
 }
 
@@ -120,9 +151,18 @@ impl Buffer {
     pub fn usage(&self) -> BufferUsageFlags {
         self.inner.usage
     }
+    /// Returns the allocation, if the buffer was created by an
+    /// [`Allocator`](crate::vk::Allocator).
+    pub(crate) fn allocation(&self) -> Option<&Allocation> {
+        self.allocation.as_ref()
+    }
 }
 
 impl BufferWithoutMemory {
+    /// Borrows the inner Vulkan handle.
+    pub fn handle(&self) -> Ref<'_, VkBuffer> {
+        self.handle.borrow()
+    }
     /// Borrows the inner Vulkan handle.
     pub fn borrow_mut(&mut self) -> Mut<VkBuffer> {
         self.handle.borrow_mut()
@@ -144,7 +184,7 @@ impl BufferWithoutMemory {
                 &mut result,
             );
         }
-        if !self.device.enabled().robust_buffer_access.as_bool()
+        if !self.device.enabled().features.robust_buffer_access.as_bool()
             && self.usage.indexable()
         {
             result.clear_host_visible_types(
@@ -153,9 +193,83 @@ impl BufferWithoutMemory {
         }
         result
     }
-    /// Allocate a single piece of memory for the buffer and bind it. Note that
-    /// it is an error to bind a uniform, storage, vertex, or index buffer to
-    /// host-visible memory when robust buffer access is not enabled.
+    /// Returns whether the implementation prefers or requires the buffer to
+    /// have its own allocation. If neither Vulkan 1.1 nor
+    /// VK_KHR_get_memory_requirements2 is available, returns that neither is
+    /// the case.
+    #[doc = crate::man_link!(vkGetBufferMemoryRequirements2)]
+    pub fn dedicated_requirements(&self) -> MemoryDedicatedRequirements {
+        let mut dedicated = MemoryDedicatedRequirements::default();
+        if let Some(get_requirements2) =
+            self.device.fun.get_buffer_memory_requirements2
+        {
+            let mut result = MemoryRequirements2 {
+                next: Some(&mut dedicated),
+                ..Default::default()
+            };
+            unsafe {
+                (get_requirements2)(
+                    self.device.handle(),
+                    &BufferMemoryRequirementsInfo2 {
+                        stype: Default::default(),
+                        next: Default::default(),
+                        buffer: self.handle.borrow(),
+                    },
+                    &mut result,
+                );
+            }
+        }
+        dedicated
+    }
+    /// Bind the buffer to `memory` at `offset`, which may be shared with
+    /// other buffers and images. The memory is kept alive until the buffer is
+    /// dropped. Returns [`Error::InvalidArgument`] if the memory does not meet
+    /// the buffer's [`memory_requirements`](Self::memory_requirements()) at
+    /// `offset`, or if the buffer would be within
+    /// [`buffer_image_granularity`](PhysicalDeviceLimits::buffer_image_granularity)
+    /// of an optimally tiled image bound to the memory, or if the buffer
+    /// requires a [dedicated](Self::dedicated_requirements()) allocation, or if
+    /// the memory is exportable or imported and the buffer was not created
+    /// with [`new_external`](Self::new_external()) with a matching handle
+    /// type. Note that it is an error to bind a uniform, storage, vertex, or
+    /// index buffer to host-visible memory when robust buffer access is not
+    /// enabled.
+    ///
+    /// Panics if `memory` belongs to a different device.
+    #[doc = crate::man_link!(vkBindBufferMemory)]
+    pub fn bind_memory(
+        self, memory: &DeviceMemory, offset: u64,
+    ) -> ResultAndSelf<Arc<Buffer>, Self> {
+        assert_eq!(memory.device(), &self.device);
+        let req = self.memory_requirements();
+        let size = req.size;
+        let granularity = self.device.limits().buffer_image_granularity;
+        let mut bindings = memory.bindings();
+        if !memory.check(offset, req)
+            || !memory.external_compatible(self.external_handle_types)
+            || !bindings.check(offset, size, true, granularity)
+            || self
+                .dedicated_requirements()
+                .requires_dedicated_allocation
+                .as_bool()
+        {
+            return Err(ErrorAndSelf(Error::InvalidArgument, self));
+        }
+        let buffer = Buffer::bind_buffer_impl(self, memory, offset, None)?;
+        bindings.push(offset, size, true, &buffer);
+        Ok(buffer)
+    }
+    /// Bind the buffer to memory from an [`Allocator`](crate::vk::Allocator).
+    pub(crate) fn bind_allocation(
+        self, memory: &DeviceMemory, offset: u64, allocation: Allocation,
+    ) -> ResultAndSelf<Arc<Buffer>, Self> {
+        Buffer::bind_buffer_impl(self, memory, offset, Some(allocation))
+    }
+    /// Allocate a single piece of memory for the buffer and bind it. The
+    /// memory is a dedicated allocation if the implementation prefers or
+    /// requires it. Note that it is an error to bind a uniform, storage,
+    /// vertex, or index buffer to host-visible memory when robust buffer
+    /// access is not enabled.
     pub fn allocate_memory(
         self, memory_type_index: u32,
     ) -> ResultAndSelf<Arc<Buffer>, Self> {
@@ -163,16 +277,21 @@ impl BufferWithoutMemory {
         if (1 << memory_type_index) & mem_req.memory_type_bits == 0 {
             return Err(ErrorAndSelf(Error::InvalidArgument, self));
         }
-        let memory = match DeviceMemory::new(
+        let dedicated = self
+            .dedicated_requirements()
+            .dedicated()
+            .then(|| Dedicated::Buffer(self.handle.borrow()));
+        let memory = match DeviceMemory::allocate(
             &self.device,
             mem_req.size,
             memory_type_index,
+            AllocateOptions { dedicated, ..Default::default() },
         ) {
             Ok(memory) => memory,
             Err(err) => return Err(ErrorAndSelf(err, self)),
         };
         // Don't need to check requirements
-        Buffer::bind_buffer_impl(self, &memory, 0)
+        Buffer::bind_buffer_impl(self, &memory, 0, None)
     }
 }
 
@@ -225,4 +344,95 @@ mod test {
             .unwrap();
         assert!(buf.allocate_memory(host_mem as u32).is_err());
     }
+    #[test]
+    fn shared_memory() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let info = BufferCreateInfo {
+            size: 256,
+            usage: vk::BufferUsageFlags::TRANSFER_SRC,
+            ..Default::default()
+        };
+        let buf = vk::BufferWithoutMemory::new(&dev, &info)?;
+        let req = buf.memory_requirements();
+        let size = req.size.max(req.alignment);
+        let mem = vk::DeviceMemory::new(
+            &dev,
+            size * 2,
+            req.memory_type_bits.trailing_zeros(),
+        )?;
+        let buf = buf.bind_memory(&mem, 0)?;
+        let buf2 = vk::BufferWithoutMemory::new(&dev, &info)?;
+        let buf2 = buf2.bind_memory(&mem, size * 2).unwrap_err().1;
+        let buf2 = buf2.bind_memory(&mem, size)?;
+        drop(mem);
+        assert!(buf.bounds_check(0, 256) && buf2.bounds_check(0, 256));
+        Ok(())
+    }
+    #[test]
+    fn dedicated() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let buf = vk::BufferWithoutMemory::new(
+            &dev,
+            &BufferCreateInfo { size: 256, ..Default::default() },
+        )?;
+        let req = buf.memory_requirements();
+        let mem_type = req.memory_type_bits.trailing_zeros();
+        assert!(
+            vk::DeviceMemory::with_priority(&dev, 256, mem_type, 2.0).is_err()
+        );
+        buf.allocate_memory(mem_type)?;
+        Ok(())
+    }
+    #[test]
+    fn priority() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        assert_eq!(
+            vk::DeviceMemory::with_priority(&dev, 256, 0, 0.5).unwrap_err(),
+            vk::Error::ExtensionNotPresent
+        );
+        let inst = vk::Instance::new(&vk::InstanceCreateInfo {
+            application_info: Some(&vk::ApplicationInfo {
+                api_version: 1 << 22 | 1 << 12,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        if phy.api_version() < 1 << 22 | 1 << 12
+            || !phy.features2()?.memory_priority.memory_priority.as_bool()
+        {
+            return Ok(());
+        }
+        let (dev, _) = vk::Device::new(
+            &phy,
+            &vk::DeviceCreateInfo {
+                queue_create_infos: vk::slice(&[vk::DeviceQueueCreateInfo {
+                    queue_priorities: vk::slice(&[1.0]),
+                    ..Default::default()
+                }]),
+                enabled_extension_names: vk::slice(&[vk::ext::MEMORY_PRIORITY]),
+                next: (&vk::DeviceFeatures {
+                    memory_priority:
+                        vk::PhysicalDeviceMemoryPriorityFeaturesEXT {
+                            memory_priority: vk::True,
+                            ..Default::default()
+                        },
+                    ..Default::default()
+                })
+                    .into(),
+                ..Default::default()
+            },
+        )?;
+        assert!(dev.is_extension_enabled(vk::ext::MEMORY_PRIORITY));
+        let buf = vk::BufferWithoutMemory::new(
+            &dev,
+            &BufferCreateInfo { size: 256, ..Default::default() },
+        )?;
+        let req = buf.memory_requirements();
+        let mem_type = req.memory_type_bits.trailing_zeros();
+        let mem =
+            vk::DeviceMemory::with_priority(&dev, req.size, mem_type, 0.5)?;
+        buf.bind_memory(&mem, 0)?;
+        Ok(())
+    }
 }
@@ -117,6 +117,10 @@ impl CleanupQueue {
 }
 
 impl Cleanup {
+    /// A cleanup with nothing to clean up.
+    pub fn empty() -> Self {
+        CleanupQueue::new(1).new_cleanup()
+    }
     pub fn cleanup(&self) {
         let mut cursor = self.cursor;
         let array = match self.array.upgrade() {
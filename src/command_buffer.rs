@@ -17,6 +17,7 @@ use crate::error::{Error, ErrorAndSelf, Result, ResultAndSelf};
 use crate::exclusive::Exclusive;
 use crate::framebuffer::Framebuffer;
 use crate::pipeline::Pipeline;
+use crate::query_pool::QueryPool;
 use crate::render_pass::RenderPass;
 use crate::subobject::{Owner, Subobject};
 use crate::types::*;
@@ -25,6 +26,7 @@ mod transfer;
 pub mod barrier;
 mod bind;
 mod draw;
+mod query;
 
 /// A command pool.
 ///
@@ -86,6 +88,8 @@ pub struct CommandRecording<'a> {
     scratch: &'a bumpalo::Bump,
     graphics: Bindings<'a>,
     compute: Bindings<'a>,
+    /// Queries begun and not yet ended.
+    active_queries: bumpalo::collections::Vec<'a, (Arc<QueryPool>, u32)>,
     buffer: Owner<CommandBufferLifetime>,
 }
 
@@ -95,6 +99,8 @@ pub struct RenderPassRecording<'a> {
     rec: CommandRecording<'a>,
     pass: Arc<RenderPass>,
     subpass: u32,
+    render_area: Rect2D,
+    layers: u32,
 }
 
 /// An in-progress command buffer recording, inside a render pass whose contents
@@ -104,6 +110,8 @@ pub struct ExternalRenderPassRecording<'a> {
     rec: CommandRecording<'a>,
     pass: Arc<RenderPass>,
     subpass: u32,
+    render_area: Rect2D,
+    layers: u32,
 }
 
 /// An in-progress secondary command buffer recording, inside a render pass.
@@ -127,6 +135,8 @@ struct CommandPoolLifetime {
     handle: Handle<VkCommandPool>,
     resources: Vec<Arc<dyn Send + Sync + Debug>>,
     device: Arc<Device>,
+    /// The `timestamp_valid_bits` of the pool's queue family.
+    timestamp_valid_bits: u32,
 }
 
 #[derive(Debug)]
@@ -163,11 +173,14 @@ impl CommandPool {
             )?;
         }
         let handle = handle.unwrap();
+        let families = device.physical_device().queue_family_properties();
 
         let res = Owner::new(CommandPoolLifetime {
             handle,
             resources: vec![],
             device: device.clone(),
+            timestamp_valid_bits: families[queue_family_index as usize]
+                .timestamp_valid_bits,
         });
         let _res = Subobject::new(&res);
         Ok(CommandPool {
@@ -333,6 +346,7 @@ impl CommandPool {
             recording: self.recording.as_ref().unwrap(),
             graphics: Bindings::new(scratch),
             compute: Bindings::new(scratch),
+            active_queries: bumpalo::vec![in scratch],
             scratch,
             buffer: inner,
         })
@@ -399,6 +413,7 @@ impl CommandPool {
                 recording: self.recording.as_ref().unwrap(),
                 graphics: Bindings::new(scratch),
                 compute: Bindings::new(scratch),
+                active_queries: bumpalo::vec![in scratch],
                 scratch,
                 buffer: inner,
             },
@@ -521,6 +536,8 @@ impl<'a> CommandRecording<'a> {
             rec: self,
             pass: render_pass.clone(),
             subpass: 0,
+            render_area: *render_area,
+            layers: framebuffer.layers(),
         })
     }
     /// Begins a render pass recorded in secondary command buffers. Returns
@@ -546,6 +563,8 @@ impl<'a> CommandRecording<'a> {
             rec: self,
             pass: render_pass.clone(),
             subpass: 0,
+            render_area: *render_area,
+            layers: framebuffer.layers(),
         })
     }
     fn begin_render_pass_impl(
@@ -613,6 +632,8 @@ impl<'a> RenderPassRecording<'a> {
             rec: self.rec,
             pass: self.pass,
             subpass: self.subpass + 1,
+            render_area: self.render_area,
+            layers: self.layers,
         })
     }
     /// Ends the render pass. Returns [`Error::InvalidState`] if this is not the
@@ -665,6 +686,8 @@ impl<'a> ExternalRenderPassRecording<'a> {
             rec: self.rec,
             pass: self.pass,
             subpass: self.subpass + 1,
+            render_area: self.render_area,
+            layers: self.layers,
         })
     }
     /// Ends the render pass. Returns [`Error::InvalidState`] if this is not the
@@ -801,4 +824,59 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn clear_attachments_bounds() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let pass = vk::RenderPass::new(
+            &dev,
+            &vk::RenderPassCreateInfo {
+                subpasses: vk::slice(&[Default::default()]),
+                ..Default::default()
+            },
+        )?;
+        let fb = vk::Framebuffer::new(
+            &pass,
+            Default::default(),
+            vec![],
+            vk::Extent3D { width: 4, height: 4, depth: 1 },
+        )?;
+        let mut pool = vk::CommandPool::new(&dev, 0)?;
+        let buf = pool.allocate()?;
+        let rec = pool.begin(buf)?;
+        let mut rec = rec.begin_render_pass(
+            &pass,
+            &fb,
+            &vk::Rect2D {
+                offset: Default::default(),
+                extent: vk::Extent2D { width: 2, height: 2 },
+            },
+            Default::default(),
+        )?;
+        let rect = |x, width, base_array_layer| vk::ClearRect {
+            rect: vk::Rect2D {
+                offset: vk::Offset2D { x, y: 0 },
+                extent: vk::Extent2D { width, height: 1 },
+            },
+            base_array_layer,
+            layer_count: 1,
+        };
+        assert_eq!(
+            rec.clear_attachments(&[], &[rect(0, 0, 0)]),
+            Err(vk::Error::InvalidArgument)
+        );
+        assert_eq!(
+            rec.clear_attachments(&[], &[rect(1, 2, 0)]),
+            Err(vk::Error::OutOfBounds)
+        );
+        assert_eq!(
+            rec.clear_attachments(&[], &[rect(-1, 1, 0)]),
+            Err(vk::Error::OutOfBounds)
+        );
+        assert_eq!(
+            rec.clear_attachments(&[], &[rect(0, 2, 1)]),
+            Err(vk::Error::OutOfBounds)
+        );
+        Ok(())
+    }
 }
@@ -9,6 +9,8 @@
 #![allow(clippy::too_many_arguments)]
 use crate::buffer::Buffer;
 use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::event::Event;
 use crate::ffi::Array;
 use crate::image::Image;
 use crate::types::*;
@@ -274,4 +276,80 @@ impl<'a> CommandRecording<'a> {
             )
         }
     }
+    /// The reference count of `event` is incremented.
+    #[doc = crate::man_link!(vkCmdSetEvent)]
+    pub fn set_event(&mut self, event: &Event, stage_mask: PipelineStageFlags) {
+        self.add_resource(event.inner.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_set_event)(
+                self.buffer.handle.borrow_mut(),
+                event.handle(),
+                stage_mask,
+            )
+        }
+    }
+
+    /// The reference count of `event` is incremented.
+    #[doc = crate::man_link!(vkCmdResetEvent)]
+    pub fn reset_event(
+        &mut self, event: &Event, stage_mask: PipelineStageFlags,
+    ) {
+        self.add_resource(event.inner.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_reset_event)(
+                self.buffer.handle.borrow_mut(),
+                event.handle(),
+                stage_mask,
+            )
+        }
+    }
+
+    /// The reference counts of the events and of the resources in the barriers
+    /// are incremented. Returns [`Error::InvalidArgument`] if `events` is
+    /// empty.
+    #[doc = crate::man_link!(vkCmdWaitEvents)]
+    pub fn wait_events(
+        &mut self, events: &[&Event], src_stage_mask: PipelineStageFlags,
+        dst_stage_mask: PipelineStageFlags, memory_barriers: &[MemoryBarrier],
+        buffer_memory_barriers: &[BufferMemoryBarrier],
+        image_memory_barriers: &[ImageMemoryBarrier],
+    ) -> Result<()> {
+        let vk_events = self
+            .scratch
+            .alloc_slice_fill_iter(events.iter().map(|e| e.handle()));
+        let vk_events =
+            Array::from_slice(vk_events).ok_or(Error::InvalidArgument)?;
+        for e in events {
+            self.add_resource(e.inner.clone());
+        }
+        for b in buffer_memory_barriers {
+            self.add_resource(b.buffer.clone());
+        }
+        for b in image_memory_barriers {
+            self.add_resource(b.image.clone());
+        }
+        let vk_buffer_barriers = self.scratch.alloc_slice_fill_iter(
+            buffer_memory_barriers.iter().map(|b| b.vk()),
+        );
+        let vk_image_barriers = self.scratch.alloc_slice_fill_iter(
+            image_memory_barriers.iter().map(|b| b.vk()),
+        );
+
+        unsafe {
+            (self.pool.device.fun.cmd_wait_events)(
+                self.buffer.handle.borrow_mut(),
+                events.len() as u32,
+                vk_events,
+                src_stage_mask,
+                dst_stage_mask,
+                memory_barriers.len() as u32,
+                Array::from_slice(memory_barriers),
+                vk_buffer_barriers.len() as u32,
+                Array::from_slice(vk_buffer_barriers),
+                vk_image_barriers.len() as u32,
+                Array::from_slice(vk_image_barriers),
+            )
+        }
+        Ok(())
+    }
 }
@@ -379,6 +379,7 @@ mod test {
                 extent: vk::Extent3D { width: 512, height: 512, depth: 1 },
                 format: vk::Format::R8G8B8A8_SRGB,
                 mip_levels: 10,
+                usage: vk::ImageUsageFlags::TRANSFER_DST,
                 ..Default::default()
             },
         )?
@@ -445,6 +446,59 @@ mod test {
                 }]
             )
             .is_err());
+        for image_extent in [
+            vk::Extent3D { width: 8, height: 0, depth: 1 },
+            vk::Extent3D { width: 8, height: 8, depth: 0 },
+        ] {
+            assert_eq!(
+                rec.copy_buffer_to_image(
+                    &buf,
+                    &img,
+                    vk::ImageLayout::GENERAL,
+                    &[vk::BufferImageCopy {
+                        image_extent,
+                        ..Default::default()
+                    }]
+                ),
+                Err(vk::Error::InvalidArgument)
+            );
+        }
+        assert_eq!(
+            rec.copy_buffer_to_image(
+                &buf,
+                &img,
+                vk::ImageLayout::GENERAL,
+                &[vk::BufferImageCopy {
+                    image_extent: vk::Extent3D {
+                        width: 8,
+                        height: 8,
+                        depth: 1
+                    },
+                    image_subresource: vk::ImageSubresourceLayers {
+                        layer_count: 0,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }]
+            ),
+            Err(vk::Error::InvalidArgument)
+        );
+        assert!(rec
+            .copy_buffer_to_image(
+                &buf,
+                &img,
+                vk::ImageLayout::GENERAL,
+                &[vk::BufferImageCopy {
+                    buffer_row_length: 4,
+                    image_extent: vk::Extent3D {
+                        width: 8,
+                        height: 8,
+                        depth: 1
+                    },
+                    ..Default::default()
+                }]
+            )
+            .is_err());
         assert!(rec
             .copy_buffer_to_image(
                 &buf,
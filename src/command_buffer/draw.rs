@@ -121,7 +121,9 @@ impl<'a> RenderPassRecording<'a> {
     }
     #[doc = draw_state!()]
     ///
-    /// The reference count of `buffer` is incremented.
+    /// The reference count of `buffer` is incremented. Returns
+    /// [`Error::MissingFeatures`] if `draw_count` is greater than 1 and the
+    /// `multi_draw_indirect` feature is not enabled.
     ///
     #[doc = crate::man_link!(vkCmdDrawIndirect)]
     pub fn draw_indirect(
@@ -149,7 +151,9 @@ impl<'a> RenderPassRecording<'a> {
     }
     #[doc = draw_state!()]
     ///
-    /// The reference count of `buffer` is incremented.
+    /// The reference count of `buffer` is incremented. Returns
+    /// [`Error::MissingFeatures`] if `draw_count` is greater than 1 and the
+    /// `multi_draw_indirect` feature is not enabled.
     ///
     #[doc = crate::man_link!(vkCmdDrawIndexedIndirect)]
     pub fn draw_indexed_indirect(
@@ -178,7 +182,9 @@ impl<'a> SecondaryCommandRecording<'a> {
     }
     #[doc = draw_state!()]
     ///
-    /// The reference count of `buffer` is incremented.
+    /// The reference count of `buffer` is incremented. Returns
+    /// [`Error::MissingFeatures`] if `draw_count` is greater than 1 and the
+    /// `multi_draw_indirect` feature is not enabled.
     ///
     #[doc = crate::man_link!(vkCmdDrawIndirect)]
     pub fn draw_indirect(
@@ -206,7 +212,9 @@ impl<'a> SecondaryCommandRecording<'a> {
     }
     #[doc = draw_state!()]
     ///
-    /// The reference count of `buffer` is incremented.
+    /// The reference count of `buffer` is incremented. Returns
+    /// [`Error::MissingFeatures`] if `draw_count` is greater than 1 and the
+    /// `multi_draw_indirect` feature is not enabled.
     ///
     #[doc = crate::man_link!(vkCmdDrawIndexedIndirect)]
     pub fn draw_indexed_indirect(
@@ -236,6 +244,16 @@ fn bounds_check_n(
 }
 
 impl<'a> CommandRecording<'a> {
+    fn check_draw_count(&self, draw_count: u32) -> Result<()> {
+        let device = &self.pool.device;
+        if draw_count > 1 {
+            device.require_feature(|f| &mut f.features.multi_draw_indirect)?;
+        }
+        if draw_count > device.limits().max_draw_indirect_count {
+            return Err(Error::LimitExceeded);
+        }
+        Ok(())
+    }
     fn draw(
         &mut self, vertex_count: u32, instance_count: u32, first_vertex: u32,
         first_instance: u32,
@@ -260,6 +278,7 @@ impl<'a> CommandRecording<'a> {
             return Err(Error::InvalidArgument);
         }
         bounds_check_n(draw_count, 16, stride, buffer, offset)?;
+        self.check_draw_count(draw_count)?;
         self.graphics.check()?;
         self.add_resource(buffer.clone());
         unsafe {
@@ -295,6 +314,7 @@ impl<'a> CommandRecording<'a> {
         stride: u32,
     ) -> Result<()> {
         bounds_check_n(draw_count, 20, stride, buffer, offset)?;
+        self.check_draw_count(draw_count)?;
         if !buffer.usage().contains(BufferUsageFlags::INDIRECT_BUFFER) {
             return Err(Error::InvalidArgument);
         }
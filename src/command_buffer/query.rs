@@ -0,0 +1,265 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(clippy::too_many_arguments)]
+use crate::buffer::Buffer;
+use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::query_pool::QueryPool;
+use crate::types::*;
+
+use super::{CommandRecording, RenderPassRecording, SecondaryCommandRecording};
+
+macro_rules! begin_query_doc {
+    () => {
+        "The reference count of `pool` is incremented. Returns
+        [`Error::OutOfBounds`] if `query` is out of bounds. Returns
+        [`Error::InvalidArgument`] if `pool` is a timestamp query pool, or if
+        `flags` includes `PRECISE` and `pool` is not an occlusion query pool.
+        Returns [`Error::MissingFeatures`] if `flags` includes `PRECISE` and the
+        `occlusion_query_precise` feature is not enabled. Returns
+        [`Error::InvalidState`] if the query is already active."
+    };
+}
+
+macro_rules! end_query_doc {
+    () => {
+        "The reference count of `pool` is incremented. Returns
+        [`Error::OutOfBounds`] if `query` is out of bounds, and
+        [`Error::InvalidState`] if the query was not begun in this command
+        buffer or has already been ended."
+    };
+}
+
+macro_rules! write_timestamp_doc {
+    () => {
+        "The reference count of `pool` is incremented. Returns
+        [`Error::OutOfBounds`] if `query` is out of bounds. Returns
+        [`Error::InvalidArgument`] if `pool` is not a timestamp query pool, and
+        [`Error::InvalidState`] if the command pool's queue family does not
+        support timestamps."
+    };
+}
+
+impl<'a> RenderPassRecording<'a> {
+    #[doc = begin_query_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdBeginQuery)]
+    pub fn begin_query(
+        &mut self, pool: &Arc<QueryPool>, query: u32, flags: QueryControlFlags,
+    ) -> Result<()> {
+        self.rec.begin_query(pool, query, flags)
+    }
+    #[doc = end_query_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdEndQuery)]
+    pub fn end_query(
+        &mut self, pool: &Arc<QueryPool>, query: u32,
+    ) -> Result<()> {
+        self.rec.end_query(pool, query)
+    }
+    #[doc = write_timestamp_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn write_timestamp(
+        &mut self, stage: PipelineStageFlags, pool: &Arc<QueryPool>, query: u32,
+    ) -> Result<()> {
+        self.rec.write_timestamp(stage, pool, query)
+    }
+}
+impl<'a> SecondaryCommandRecording<'a> {
+    #[doc = begin_query_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdBeginQuery)]
+    pub fn begin_query(
+        &mut self, pool: &Arc<QueryPool>, query: u32, flags: QueryControlFlags,
+    ) -> Result<()> {
+        self.rec.begin_query(pool, query, flags)
+    }
+    #[doc = end_query_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdEndQuery)]
+    pub fn end_query(
+        &mut self, pool: &Arc<QueryPool>, query: u32,
+    ) -> Result<()> {
+        self.rec.end_query(pool, query)
+    }
+    #[doc = write_timestamp_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn write_timestamp(
+        &mut self, stage: PipelineStageFlags, pool: &Arc<QueryPool>, query: u32,
+    ) -> Result<()> {
+        self.rec.write_timestamp(stage, pool, query)
+    }
+}
+
+impl<'a> CommandRecording<'a> {
+    #[doc = begin_query_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdBeginQuery)]
+    pub fn begin_query(
+        &mut self, pool: &Arc<QueryPool>, query: u32, flags: QueryControlFlags,
+    ) -> Result<()> {
+        if query >= pool.len() {
+            return Err(Error::OutOfBounds);
+        }
+        let precise = flags.contains(QueryControlFlags::PRECISE);
+        if pool.query_type() == QueryType::TIMESTAMP
+            || (precise && pool.query_type() != QueryType::OCCLUSION)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        if precise {
+            self.pool
+                .device
+                .require_feature(|f| &mut f.features.occlusion_query_precise)?;
+        }
+        if self.active_query(pool, query).is_some() {
+            return Err(Error::InvalidState);
+        }
+        self.active_queries.push((pool.clone(), query));
+        self.add_resource(pool.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_begin_query)(
+                self.buffer.handle.borrow_mut(),
+                pool.handle(),
+                query,
+                flags,
+            )
+        }
+        Ok(())
+    }
+    #[doc = end_query_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdEndQuery)]
+    pub fn end_query(
+        &mut self, pool: &Arc<QueryPool>, query: u32,
+    ) -> Result<()> {
+        if query >= pool.len() {
+            return Err(Error::OutOfBounds);
+        }
+        let index =
+            self.active_query(pool, query).ok_or(Error::InvalidState)?;
+        self.active_queries.swap_remove(index);
+        self.add_resource(pool.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_end_query)(
+                self.buffer.handle.borrow_mut(),
+                pool.handle(),
+                query,
+            )
+        }
+        Ok(())
+    }
+    #[doc = write_timestamp_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn write_timestamp(
+        &mut self, stage: PipelineStageFlags, pool: &Arc<QueryPool>, query: u32,
+    ) -> Result<()> {
+        if query >= pool.len() {
+            return Err(Error::OutOfBounds);
+        }
+        if pool.query_type() != QueryType::TIMESTAMP {
+            return Err(Error::InvalidArgument);
+        }
+        if self.pool.timestamp_valid_bits == 0 {
+            return Err(Error::InvalidState);
+        }
+        self.add_resource(pool.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_write_timestamp)(
+                self.buffer.handle.borrow_mut(),
+                stage,
+                pool.handle(),
+                query,
+            )
+        }
+        Ok(())
+    }
+    /// Returns the index of `query` of `pool` in the active queries.
+    fn active_query(&self, pool: &Arc<QueryPool>, query: u32) -> Option<usize> {
+        self.active_queries
+            .iter()
+            .position(|(p, q)| Arc::ptr_eq(p, pool) && *q == query)
+    }
+    /// The reference count of `pool` is incremented. Returns
+    /// [`Error::OutOfBounds`] if the queries are out of bounds.
+    #[doc = crate::man_link!(vkCmdResetQueryPool)]
+    pub fn reset_query_pool(
+        &mut self, pool: &Arc<QueryPool>, first_query: u32, query_count: u32,
+    ) -> Result<()> {
+        if !pool.bounds_check(first_query, query_count) {
+            return Err(Error::OutOfBounds);
+        }
+        self.add_resource(pool.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_reset_query_pool)(
+                self.buffer.handle.borrow_mut(),
+                pool.handle(),
+                first_query,
+                query_count,
+            )
+        }
+        Ok(())
+    }
+    /// The reference counts of `pool` and `dst` are incremented. Each query
+    /// writes [`QueryPool::values_per_query`] values, plus one if `flags`
+    /// includes `WITH_AVAILABILITY`, at intervals of `stride` bytes. Returns
+    /// [`Error::OutOfBounds`] if the queries or the destination range are out of
+    /// bounds. Returns [`Error::InvalidArgument`] if `query_count` is zero,
+    /// `dst_offset` or `stride` is not a multiple of the result size, `stride`
+    /// is too small, or `dst` does not have the `TRANSFER_DST` usage flag.
+    #[doc = crate::man_link!(vkCmdCopyQueryPoolResults)]
+    pub fn copy_query_pool_results(
+        &mut self, pool: &Arc<QueryPool>, first_query: u32, query_count: u32,
+        dst: &Arc<Buffer>, dst_offset: u64, stride: u64,
+        flags: QueryResultFlags,
+    ) -> Result<()> {
+        let value_size =
+            if flags.contains(QueryResultFlags::_64) { 8 } else { 4 };
+        let mut values = pool.values_per_query() as u64;
+        if flags.contains(QueryResultFlags::WITH_AVAILABILITY) {
+            values += 1;
+        }
+        let size = values * value_size;
+        if query_count == 0
+            || dst_offset % value_size != 0
+            || stride % value_size != 0
+            || (query_count > 1 && stride < size)
+            || !dst.usage().contains(BufferUsageFlags::TRANSFER_DST)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        let len = stride
+            .checked_mul(query_count as u64 - 1)
+            .and_then(|l| l.checked_add(size))
+            .ok_or(Error::OutOfBounds)?;
+        if !pool.bounds_check(first_query, query_count)
+            || !dst.bounds_check(dst_offset, len)
+        {
+            return Err(Error::OutOfBounds);
+        }
+        self.add_resource(pool.clone());
+        self.add_resource(dst.clone());
+        unsafe {
+            (self.pool.device.fun.cmd_copy_query_pool_results)(
+                self.buffer.handle.borrow_mut(),
+                pool.handle(),
+                first_query,
+                query_count,
+                dst.handle(),
+                dst_offset,
+                stride,
+                flags,
+            )
+        }
+        Ok(())
+    }
+}
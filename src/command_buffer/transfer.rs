@@ -10,10 +10,12 @@ use crate::buffer::Buffer;
 use crate::enums::*;
 use crate::error::{Error, Result};
 use crate::ffi::Array;
+use crate::format::NumericType;
 use crate::image::Image;
+use crate::render_pass::RenderPass;
 use crate::types::*;
 
-use super::CommandRecording;
+use super::{CommandRecording, RenderPassRecording, SecondaryCommandRecording};
 
 impl<'a> CommandRecording<'a> {
     /// The reference count of `dst` is incremented. Offset and size are rounded
@@ -51,6 +53,35 @@ impl<'a> CommandRecording<'a> {
         Ok(())
     }
 
+    /// The reference count of `dst` is incremented. `data` is copied into the
+    /// command pool's scratch memory. Returns [`Error::OutOfBounds`] if the
+    /// update is out of bounds. Returns [`Error::InvalidArgument`] if `data` is
+    /// empty or larger than 65536 bytes, or if `offset` or the length of `data`
+    /// is not a multiple of 4.
+    #[doc = crate::man_link!(vkCmdUpdateBuffer)]
+    pub fn update_buffer(
+        &mut self, dst: &Arc<Buffer>, offset: u64, data: &[u8],
+    ) -> Result<()> {
+        if data.len() > 65536 || data.len() & 3 != 0 || offset & 3 != 0 {
+            return Err(Error::InvalidArgument);
+        }
+        if !dst.bounds_check(offset, data.len() as u64) {
+            return Err(Error::OutOfBounds);
+        }
+        let data = self.scratch.alloc_slice_copy(data);
+        unsafe {
+            (self.pool.device.fun.cmd_update_buffer)(
+                self.buffer.handle.borrow_mut(),
+                dst.handle(),
+                offset,
+                data.len() as u64,
+                Array::from_slice(data).ok_or(Error::InvalidArgument)?,
+            );
+        }
+        self.add_resource(dst.clone());
+        Ok(())
+    }
+
     /// The reference counts of `src` and `dst` are incremented.
     /// Returns [`Error::OutOfBounds`] if a region is out of bounds.
     #[doc = crate::man_link!(vkCmdCopyBuffer)]
@@ -80,17 +111,19 @@ impl<'a> CommandRecording<'a> {
 
     /// The reference counts of `src` and `dst` are incremented.
     /// Returns [`Error::OutOfBounds`] if a region is out of bounds. Returns
-    /// [`Error::InvalidArgument`] if `regions` is empty.
+    /// [`Error::InvalidArgument`] if `regions` is empty, a region's layer count
+    /// or a component of its extent is zero, its aspect mask
+    /// is not exactly one aspect of `dst`'s format, its buffer row length or
+    /// image height is nonzero and smaller than the image extent, or its
+    /// buffer offset is not aligned to the texel block size (or to 4 for depth
+    /// and stencil aspects).
     #[doc = crate::man_link!(vkCmdCopyBufferToImage)]
     pub fn copy_buffer_to_image(
         &mut self, src: &Arc<Buffer>, dst: &Arc<Image>,
         dst_layout: ImageLayout, regions: &[BufferImageCopy],
     ) -> Result<()> {
         for r in regions {
-            let bytes = image_byte_size_3d(dst.format(), r.image_extent)
-                .ok_or(Error::OutOfBounds)?
-                .checked_mul(r.image_subresource.layer_count as u64)
-                .ok_or(Error::OutOfBounds)?;
+            let bytes = buffer_copy_size(dst.format(), r)?;
             if !dst.bounds_check(
                 r.image_subresource.mip_level,
                 r.image_offset,
@@ -120,13 +153,170 @@ impl<'a> CommandRecording<'a> {
 
     /// The reference counts of `src` and `dst` are incremented.
     /// Returns [`Error::OutOfBounds`] if a region is out of bounds. Returns
-    /// [`Error::InvalidArgument`] if `regions` is empty.
+    /// [`Error::InvalidArgument`] if `regions` is empty, `src` does not have
+    /// the `TRANSFER_SRC` usage flag, `dst` does not have the `TRANSFER_DST`
+    /// usage flag, or a region is invalid for `src`'s format as described in
+    /// [`copy_buffer_to_image`](Self::copy_buffer_to_image).
+    #[doc = crate::man_link!(vkCmdCopyImageToBuffer)]
+    pub fn copy_image_to_buffer(
+        &mut self, src: &Arc<Image>, src_layout: ImageLayout,
+        dst: &Arc<Buffer>, regions: &[BufferImageCopy],
+    ) -> Result<()> {
+        if !src.usage().contains(ImageUsageFlags::TRANSFER_SRC)
+            || !dst.usage().contains(BufferUsageFlags::TRANSFER_DST)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        for r in regions {
+            let bytes = buffer_copy_size(src.format(), r)?;
+            if !src.bounds_check(
+                r.image_subresource.mip_level,
+                r.image_offset,
+                r.image_extent,
+            ) || !src.array_bounds_check(
+                r.image_subresource.base_array_layer,
+                r.image_subresource.layer_count,
+            ) || !dst.bounds_check(r.buffer_offset, bytes)
+            {
+                return Err(Error::OutOfBounds);
+            }
+        }
+        unsafe {
+            (self.pool.device.fun.cmd_copy_image_to_buffer)(
+                self.buffer.handle.borrow_mut(),
+                src.handle(),
+                src_layout,
+                dst.handle(),
+                regions.len() as u32,
+                Array::from_slice(regions).ok_or(Error::InvalidArgument)?,
+            );
+        }
+        self.add_resource(src.clone());
+        self.add_resource(dst.clone());
+        Ok(())
+    }
+
+    /// The reference counts of `src` and `dst` are incremented.
+    /// Returns [`Error::OutOfBounds`] if a region is out of bounds. Returns
+    /// [`Error::InvalidArgument`] if `regions` is empty, the images' formats
+    /// are not size-compatible, their sample counts differ, a region's aspect
+    /// masks differ or are not aspects of the images' formats, `src` does not
+    /// have the `TRANSFER_SRC` usage flag, or `dst` does not have the
+    /// `TRANSFER_DST` usage flag.
+    #[doc = crate::man_link!(vkCmdCopyImage)]
+    pub fn copy_image(
+        &mut self, src: &Arc<Image>, src_layout: ImageLayout, dst: &Arc<Image>,
+        dst_layout: ImageLayout, regions: &[ImageCopy],
+    ) -> Result<()> {
+        if !src.usage().contains(ImageUsageFlags::TRANSFER_SRC)
+            || !dst.usage().contains(ImageUsageFlags::TRANSFER_DST)
+            || !copy_compatible(src.format(), dst.format())
+            || src.samples() != dst.samples()
+        {
+            return Err(Error::InvalidArgument);
+        }
+        for r in regions {
+            if r.src_subresource.layer_count != r.dst_subresource.layer_count
+                || r.src_subresource.aspect_mask
+                    != r.dst_subresource.aspect_mask
+                || !aspects_valid(src.format(), r.src_subresource.aspect_mask)
+                || !aspects_valid(dst.format(), r.dst_subresource.aspect_mask)
+            {
+                return Err(Error::InvalidArgument);
+            }
+            if !src.array_bounds_check(
+                r.src_subresource.base_array_layer,
+                r.src_subresource.layer_count,
+            ) || !dst.array_bounds_check(
+                r.dst_subresource.base_array_layer,
+                r.dst_subresource.layer_count,
+            ) || !src.bounds_check(
+                r.src_subresource.mip_level,
+                r.src_offset,
+                r.extent,
+            ) || !dst.bounds_check(
+                r.dst_subresource.mip_level,
+                r.dst_offset,
+                r.extent,
+            ) {
+                return Err(Error::OutOfBounds);
+            }
+        }
+        unsafe {
+            (self.pool.device.fun.cmd_copy_image)(
+                self.buffer.handle.borrow_mut(),
+                src.handle(),
+                src_layout,
+                dst.handle(),
+                dst_layout,
+                regions.len() as u32,
+                Array::from_slice(regions).ok_or(Error::InvalidArgument)?,
+            );
+        }
+        self.add_resource(src.clone());
+        self.add_resource(dst.clone());
+        Ok(())
+    }
+
+    /// The reference counts of `src` and `dst` are incremented.
+    /// Returns [`Error::OutOfBounds`] if a region is out of bounds. Returns
+    /// [`Error::InvalidArgument`] if `regions` is empty, `src` does not have
+    /// the `TRANSFER_SRC` usage flag, `dst` does not have the `TRANSFER_DST`
+    /// usage flag, either image is multisampled, a region's aspect masks are
+    /// not aspects of the images' formats or its layer counts differ, the
+    /// images have depth or stencil
+    /// formats which differ or `filter` is not nearest, or one image has an
+    /// integer format and the other does not have the same numeric type.
+    /// Returns
+    /// [`Error::FormatNotSupported`] if `src`'s format does not support
+    /// `BLIT_SRC`, `dst`'s format does not support `BLIT_DST`, or `filter` is
+    /// linear and `src`'s format does not support
+    /// `SAMPLED_IMAGE_FILTER_LINEAR`.
     #[doc = crate::man_link!(vkCmdBlitImage)]
     pub fn blit_image(
         &mut self, src: &Arc<Image>, src_layout: ImageLayout, dst: &Arc<Image>,
         dst_layout: ImageLayout, regions: &[ImageBlit], filter: Filter,
     ) -> Result<()> {
+        let src_features = src.format_features();
+        let dst_features = dst.format_features();
+        if !src_features.contains(FormatFeatureFlags::BLIT_SRC)
+            || !dst_features.contains(FormatFeatureFlags::BLIT_DST)
+            || (filter == Filter::LINEAR
+                && !src_features
+                    .contains(FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR))
+        {
+            return Err(Error::FormatNotSupported);
+        }
+        if !src.usage().contains(ImageUsageFlags::TRANSFER_SRC)
+            || !dst.usage().contains(ImageUsageFlags::TRANSFER_DST)
+            || src.samples() != SampleCount::_1
+            || dst.samples() != SampleCount::_1
+        {
+            return Err(Error::InvalidArgument);
+        }
+        let (src_format, dst_format) = (src.format(), dst.format());
+        let depth_stencil = |f: Format| f.is_depth() || f.is_stencil();
+        let integer = |f: Format| {
+            matches!(
+                f.numeric_type(),
+                Some(NumericType::Uint | NumericType::Sint)
+            )
+        };
+        if ((depth_stencil(src_format) || depth_stencil(dst_format))
+            && (src_format != dst_format || filter != Filter::NEAREST))
+            || ((integer(src_format) || integer(dst_format))
+                && src_format.numeric_type() != dst_format.numeric_type())
+        {
+            return Err(Error::InvalidArgument);
+        }
         for r in regions {
+            if !aspects_valid(src_format, r.src_subresource.aspect_mask)
+                || !aspects_valid(dst_format, r.dst_subresource.aspect_mask)
+                || r.src_subresource.layer_count
+                    != r.dst_subresource.layer_count
+            {
+                return Err(Error::InvalidArgument);
+            }
             if !src.array_bounds_check(
                 r.src_subresource.base_array_layer,
                 r.src_subresource.layer_count,
@@ -166,6 +356,76 @@ impl<'a> CommandRecording<'a> {
         Ok(())
     }
 
+    /// The reference counts of `src` and `dst` are incremented.
+    /// Returns [`Error::OutOfBounds`] if a region is out of bounds. Returns
+    /// [`Error::InvalidArgument`] if `regions` is empty, the images' formats
+    /// differ or are depth or stencil formats, `src` is not multisampled, `dst`
+    /// is multisampled, a region's aspect masks are not `COLOR`, `src` does not
+    /// have the `TRANSFER_SRC` usage flag, or `dst` does not have the
+    /// `TRANSFER_DST` usage flag. Returns [`Error::FormatNotSupported`] if
+    /// `dst`'s format does not support `COLOR_ATTACHMENT`.
+    #[doc = crate::man_link!(vkCmdResolveImage)]
+    pub fn resolve_image(
+        &mut self, src: &Arc<Image>, src_layout: ImageLayout, dst: &Arc<Image>,
+        dst_layout: ImageLayout, regions: &[ImageResolve],
+    ) -> Result<()> {
+        let format = src.format();
+        if regions.is_empty()
+            || !src.usage().contains(ImageUsageFlags::TRANSFER_SRC)
+            || !dst.usage().contains(ImageUsageFlags::TRANSFER_DST)
+            || format != dst.format()
+            || format.is_depth()
+            || format.is_stencil()
+            || src.samples() == SampleCount::_1
+            || dst.samples() != SampleCount::_1
+        {
+            return Err(Error::InvalidArgument);
+        }
+        if !dst.format_features().contains(FormatFeatureFlags::COLOR_ATTACHMENT)
+        {
+            return Err(Error::FormatNotSupported);
+        }
+        for r in regions {
+            if r.src_subresource.layer_count != r.dst_subresource.layer_count
+                || r.src_subresource.aspect_mask != ImageAspectFlags::COLOR
+                || r.dst_subresource.aspect_mask != ImageAspectFlags::COLOR
+            {
+                return Err(Error::InvalidArgument);
+            }
+            if !src.array_bounds_check(
+                r.src_subresource.base_array_layer,
+                r.src_subresource.layer_count,
+            ) || !dst.array_bounds_check(
+                r.dst_subresource.base_array_layer,
+                r.dst_subresource.layer_count,
+            ) || !src.bounds_check(
+                r.src_subresource.mip_level,
+                r.src_offset,
+                r.extent,
+            ) || !dst.bounds_check(
+                r.dst_subresource.mip_level,
+                r.dst_offset,
+                r.extent,
+            ) {
+                return Err(Error::OutOfBounds);
+            }
+        }
+        unsafe {
+            (self.pool.device.fun.cmd_resolve_image)(
+                self.buffer.handle.borrow_mut(),
+                src.handle(),
+                src_layout,
+                dst.handle(),
+                dst_layout,
+                regions.len() as u32,
+                Array::from_slice(regions).ok_or(Error::InvalidArgument)?,
+            );
+        }
+        self.add_resource(src.clone());
+        self.add_resource(dst.clone());
+        Ok(())
+    }
+
     /// The reference count of `image` is incremented. Returns
     /// [`Error::InvalidArgument`] if `ranges` is empty.
     #[doc = crate::man_link!(vkCmdClearColorImage)]
@@ -189,4 +449,216 @@ impl<'a> CommandRecording<'a> {
 
         Ok(())
     }
+
+    /// The reference count of `image` is incremented. Returns
+    /// [`Error::InvalidArgument`] if `ranges` is empty.
+    #[doc = crate::man_link!(vkCmdClearDepthStencilImage)]
+    pub fn clear_depth_stencil_image(
+        &mut self, image: &Arc<Image>, layout: ImageLayout,
+        depth_stencil: ClearDepthStencilValue,
+        ranges: &[ImageSubresourceRange],
+    ) -> Result<()> {
+        let array = Array::from_slice(ranges).ok_or(Error::InvalidArgument)?;
+        unsafe {
+            (self.pool.device.fun.cmd_clear_depth_stencil_image)(
+                self.buffer.handle.borrow_mut(),
+                image.handle(),
+                layout,
+                &depth_stencil,
+                ranges.len() as u32,
+                array,
+            )
+        }
+
+        self.add_resource(image.clone());
+
+        Ok(())
+    }
+
+    /// `bounds` is the render area and number of framebuffer layers, if known.
+    fn clear_attachments(
+        &mut self, pass: &RenderPass, subpass: u32,
+        bounds: Option<(&Rect2D, u32)>, attachments: &[ClearAttachment],
+        rects: &[ClearRect],
+    ) -> Result<()> {
+        for att in attachments {
+            let aspect = att.aspect_mask;
+            if aspect.contains(ImageAspectFlags::COLOR) {
+                if aspect != ImageAspectFlags::COLOR {
+                    return Err(Error::InvalidArgument);
+                }
+                if att.color_attachment >= pass.num_color_attachments(subpass) {
+                    return Err(Error::OutOfBounds);
+                }
+            } else if aspect.is_empty()
+                || !(ImageAspectFlags::DEPTH | ImageAspectFlags::STENCIL)
+                    .contains(aspect)
+            {
+                return Err(Error::InvalidArgument);
+            } else if !pass.has_depth_stencil_attachment(subpass) {
+                return Err(Error::OutOfBounds);
+            }
+        }
+        for rect in rects {
+            if rect.layer_count == 0
+                || rect.rect.extent.width == 0
+                || rect.rect.extent.height == 0
+            {
+                return Err(Error::InvalidArgument);
+            }
+            if let Some((area, layers)) = bounds {
+                if !rect_contains(area, &rect.rect)
+                    || rect.base_array_layer as u64 + rect.layer_count as u64
+                        > layers as u64
+                {
+                    return Err(Error::OutOfBounds);
+                }
+            }
+        }
+        unsafe {
+            (self.pool.device.fun.cmd_clear_attachments)(
+                self.buffer.handle.borrow_mut(),
+                attachments.len() as u32,
+                Array::from_slice(attachments).ok_or(Error::InvalidArgument)?,
+                rects.len() as u32,
+                Array::from_slice(rects).ok_or(Error::InvalidArgument)?,
+            )
+        }
+        Ok(())
+    }
+}
+
+macro_rules! clear_attachments_doc {
+    () => {
+        "Returns [`Error::OutOfBounds`] if an attachment to clear is not present
+        in the current subpass, or a rectangle is outside the render area or
+        the layers of the framebuffer. Returns [`Error::InvalidArgument`] if
+        `attachments` or `rects` is empty, an aspect mask is invalid, or a
+        rectangle has a width, height, or layer count of zero."
+    };
+}
+
+impl<'a> RenderPassRecording<'a> {
+    #[doc = clear_attachments_doc!()]
+    ///
+    #[doc = crate::man_link!(vkCmdClearAttachments)]
+    pub fn clear_attachments(
+        &mut self, attachments: &[ClearAttachment], rects: &[ClearRect],
+    ) -> Result<()> {
+        self.rec.clear_attachments(
+            &self.pass,
+            self.subpass,
+            Some((&self.render_area, self.layers)),
+            attachments,
+            rects,
+        )
+    }
+}
+impl<'a> SecondaryCommandRecording<'a> {
+    #[doc = clear_attachments_doc!()]
+    ///
+    /// The render area and framebuffer are not known when recording a
+    /// secondary command buffer, so rectangles are not checked against them.
+    ///
+    #[doc = crate::man_link!(vkCmdClearAttachments)]
+    pub fn clear_attachments(
+        &mut self, attachments: &[ClearAttachment], rects: &[ClearRect],
+    ) -> Result<()> {
+        self.rec.clear_attachments(
+            &self.pass,
+            self.subpass,
+            None,
+            attachments,
+            rects,
+        )
+    }
+}
+
+/// `inner` lies entirely within `outer`.
+fn rect_contains(outer: &Rect2D, inner: &Rect2D) -> bool {
+    let range =
+        |offset: i32, size: u32| (offset as i64, offset as i64 + size as i64);
+    let (ox0, ox1) = range(outer.offset.x, outer.extent.width);
+    let (oy0, oy1) = range(outer.offset.y, outer.extent.height);
+    let (ix0, ix1) = range(inner.offset.x, inner.extent.width);
+    let (iy0, iy1) = range(inner.offset.y, inner.extent.height);
+    ox0 <= ix0 && ix1 <= ox1 && oy0 <= iy0 && iy1 <= oy1
+}
+
+/// Formats are compatible for copies if they are the same, or are both color
+/// formats with the same block size and block extent.
+fn copy_compatible(a: Format, b: Format) -> bool {
+    let color = |f: Format| f.aspects() == ImageAspectFlags::COLOR;
+    a == b
+        || (color(a)
+            && color(b)
+            && a.block_size() == b.block_size()
+            && a.block_extent() == b.block_extent())
+}
+
+/// The aspect mask is nonempty and contains only aspects of the format.
+fn aspects_valid(format: Format, aspect_mask: ImageAspectFlags) -> bool {
+    !aspect_mask.is_empty() && format.aspects().contains(aspect_mask)
+}
+
+/// Validates a buffer-image copy region for an image of `format` and returns
+/// the number of bytes of the buffer it accesses, starting at
+/// `buffer_offset`.
+fn buffer_copy_size(format: Format, r: &BufferImageCopy) -> Result<u64> {
+    let aspect = r.image_subresource.aspect_mask;
+    let (texel_size, block) =
+        format.buffer_texel(aspect).ok_or(Error::InvalidArgument)?;
+    let extent = r.image_extent;
+    if r.image_subresource.layer_count == 0
+        || extent.width == 0
+        || extent.height == 0
+        || extent.depth == 0
+    {
+        return Err(Error::InvalidArgument);
+    }
+    if (r.buffer_row_length != 0 && r.buffer_row_length < extent.width)
+        || (r.buffer_image_height != 0 && r.buffer_image_height < extent.height)
+    {
+        return Err(Error::InvalidArgument);
+    }
+    let alignment = if format.is_depth() || format.is_stencil() {
+        4
+    } else {
+        texel_size as u64
+    };
+    if r.buffer_offset % alignment != 0 {
+        return Err(Error::InvalidArgument);
+    }
+    let blocks = |texels: u32, block: u32| -> u64 {
+        (texels as u64 + block as u64 - 1) / block as u64
+    };
+    let row_length = if r.buffer_row_length == 0 {
+        extent.width
+    } else {
+        r.buffer_row_length
+    };
+    let image_height = if r.buffer_image_height == 0 {
+        extent.height
+    } else {
+        r.buffer_image_height
+    };
+    let row_blocks = blocks(row_length, block.width);
+    let slice_blocks = blocks(image_height, block.height)
+        .checked_mul(row_blocks)
+        .ok_or(Error::OutOfBounds)?;
+    let slices = (r.image_subresource.layer_count as u64)
+        .checked_mul(extent.depth as u64)
+        .ok_or(Error::OutOfBounds)?;
+    // The last row of the last slice only needs to extend to the end of the
+    // region.
+    (slices - 1)
+        .checked_mul(slice_blocks)
+        .and_then(|b| {
+            b.checked_add(
+                (blocks(extent.height, block.height) - 1) * row_blocks,
+            )
+        })
+        .and_then(|b| b.checked_add(blocks(extent.width, block.width)))
+        .and_then(|b| b.checked_mul(texel_size as u64))
+        .ok_or(Error::OutOfBounds)
 }
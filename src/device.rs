@@ -8,10 +8,14 @@
 
 use std::sync::atomic::AtomicU32;
 
+use crate::enums::Bool;
 use crate::error::{Error, Result};
+use crate::ffi::{ExtensionChain, NonNull, Str};
 use crate::instance::Instance;
 use crate::load::DeviceFn;
-use crate::physical_device::PhysicalDevice;
+use crate::physical_device::{
+    DeviceFeatures, FeaturesChain, PhysicalDevice, VERSION_1_1,
+};
 use crate::queue::Queue;
 use crate::types::*;
 
@@ -21,10 +25,13 @@ pub struct Device {
     pub(crate) fun: DeviceFn,
     physical_device: PhysicalDevice,
     limits: PhysicalDeviceLimits,
-    enabled: PhysicalDeviceFeatures,
+    enabled: DeviceFeatures,
+    enabled_extensions: Vec<String>,
     memory_allocation_count: AtomicU32,
     sampler_allocation_count: AtomicU32,
     queues: Vec<u32>,
+    #[cfg(feature = "async")]
+    pub(crate) waiter: crate::fence_future::FenceWaiter,
 }
 
 impl std::fmt::Debug for Device {
@@ -54,12 +61,43 @@ impl Drop for Device {
     }
 }
 
+/// The extension structures of a [`DeviceCreateInfo`] passed to
+/// [`Device::new`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DeviceCreateInfoNext<'a> {
+    /// Features of Vulkan 1.1 and later to enable, in place of
+    /// `enabled_features`.
+    pub features: Option<&'a DeviceFeatures>,
+    /// Further extension structures, such as the features structures of
+    /// device extensions. Must not contain the structures of `features`.
+    pub chain: ExtensionChain<'a, DeviceCreateInfo<'static>>,
+}
+
+impl<'a> From<&'a DeviceFeatures> for DeviceCreateInfoNext<'a> {
+    fn from(features: &'a DeviceFeatures) -> Self {
+        Self { features: Some(features), chain: Default::default() }
+    }
+}
+
 impl Device {
     /// Create a logical device for this physical device. Queues are returned in
     /// the order requested in `info.queue_create_infos`.
+    ///
+    /// Features of Vulkan 1.1 and later can be enabled by setting
+    /// `info.next.features`, in which case `info.enabled_features` must be
+    /// `None`. Returns [`Error::InvalidArgument`] if both are set, and
+    /// [`Error::MissingFeatures`] naming every feature which is not supported
+    /// by the physical device, including features of versions higher than
+    /// [`PhysicalDevice::api_version`]. Returns [`Error::ExtensionNotPresent`]
+    /// if the features of a device extension are enabled but the extension is
+    /// not. On Vulkan 1.1 devices, the features of
+    /// [`PhysicalDeviceVulkan11Features`] are enabled with the structures that
+    /// preceded it.
     #[doc = crate::man_link!(vkCreateDevice)]
+    #[doc = crate::man_link!(VkPhysicalDeviceFeatures2)]
     pub fn new(
-        phy: &PhysicalDevice, info: &DeviceCreateInfo<'_>,
+        phy: &PhysicalDevice,
+        info: &DeviceCreateInfo<'_, DeviceCreateInfoNext<'_>>,
     ) -> Result<(Arc<Self>, Vec<Vec<Queue>>)> {
         let props = phy.queue_family_properties();
         let mut queues = vec![0; props.len()];
@@ -73,11 +111,84 @@ impl Device {
             queues[i] = q.queue_priorities.len();
         }
 
+        let api_version = phy.api_version();
+        let (enabled, supported) = match info.next.features {
+            Some(_) if info.enabled_features.is_some() => {
+                return Err(Error::InvalidArgument);
+            }
+            Some(features) if api_version >= VERSION_1_1 => {
+                (features.clone(), phy.features2()?)
+            }
+            Some(features) => (
+                features.clone(),
+                DeviceFeatures {
+                    features: phy.features(),
+                    ..Default::default()
+                },
+            ),
+            None => (
+                DeviceFeatures {
+                    features: info
+                        .enabled_features
+                        .cloned()
+                        .unwrap_or_default(),
+                    ..Default::default()
+                },
+                DeviceFeatures {
+                    features: phy.features(),
+                    ..Default::default()
+                },
+            ),
+        };
+        let missing = enabled.missing_features(&supported);
+        if !missing.is_empty() {
+            return Err(Error::MissingFeatures(missing));
+        }
+        let enabled_extensions: Vec<String> = info
+            .enabled_extension_names
+            .into_iter()
+            .map(|name| name.as_str().to_owned())
+            .collect();
+        let memory_priority = enabled.memory_priority.memory_priority.as_bool();
+        if memory_priority
+            && !enabled_extensions
+                .iter()
+                .any(|e| e == crate::ext::MEMORY_PRIORITY.as_str())
+        {
+            return Err(Error::ExtensionNotPresent);
+        }
+
+        let mut chain;
+        let mut enabled_features = info.enabled_features;
+        let mut next = info.next.chain.head();
+        let features2;
+        if let Some(features) = info.next.features {
+            if api_version >= VERSION_1_1 {
+                chain = FeaturesChain::new(features);
+                features2 = PhysicalDeviceFeatures2 {
+                    stype: Default::default(),
+                    next: chain.link(api_version, memory_priority, next),
+                    features: features.features.clone(),
+                };
+                next = Some(NonNull::from(&features2).cast());
+            } else {
+                enabled_features = Some(&features.features);
+            }
+        }
+
         let mut handle = None;
         unsafe {
             (phy.instance().fun.create_device)(
                 phy.handle(),
-                info,
+                &DeviceCreateInfo {
+                    stype: Default::default(),
+                    next,
+                    flags: info.flags,
+                    queue_create_infos: info.queue_create_infos,
+                    enabled_layer_names: info.enabled_layer_names,
+                    enabled_extension_names: info.enabled_extension_names,
+                    enabled_features,
+                },
                 None,
                 &mut handle,
             )?;
@@ -89,10 +200,13 @@ impl Device {
             fun,
             physical_device: phy.clone(),
             limits: phy.properties().limits,
-            enabled: info.enabled_features.cloned().unwrap_or_default(),
+            enabled,
+            enabled_extensions,
             memory_allocation_count: AtomicU32::new(0),
             sampler_allocation_count: AtomicU32::new(0),
             queues,
+            #[cfg(feature = "async")]
+            waiter: Default::default(),
         });
         let queues = info
             .queue_create_infos
@@ -117,9 +231,14 @@ impl Device {
         &self.limits
     }
     /// Returns the enabled features.
-    pub fn enabled(&self) -> &PhysicalDeviceFeatures {
+    pub fn enabled(&self) -> &DeviceFeatures {
         &self.enabled
     }
+    /// Returns true if the device was created with the extension `name`
+    /// enabled.
+    pub fn is_extension_enabled(&self, name: Str<'_>) -> bool {
+        self.enabled_extensions.iter().any(|e| e == name.as_str())
+    }
     /// Returns the associated phyical device.
     pub fn physical_device(&self) -> &PhysicalDevice {
         &self.physical_device
@@ -133,6 +252,20 @@ impl Device {
         let i = queue_family_index as usize;
         i < self.queues.len() && self.queues[i] >= queue_index
     }
+    /// Returns [`Error::MissingFeatures`] if the feature selected by `feature`
+    /// is not enabled.
+    pub(crate) fn require_feature(
+        &self, feature: impl Fn(&mut DeviceFeatures) -> &mut Bool,
+    ) -> Result<()> {
+        let mut required = DeviceFeatures::default();
+        *feature(&mut required) = Bool::True;
+        let missing = required.missing_features(&self.enabled);
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::MissingFeatures(missing))
+        }
+    }
     pub(crate) fn increment_memory_alloc_count(&self) -> Result<()> {
         use std::sync::atomic::Ordering;
         // Reserve allocation number 'val'.
@@ -166,3 +299,170 @@ impl Device {
         self.sampler_allocation_count.fetch_sub(1, Ordering::Relaxed);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn features2() -> vk::Result<()> {
+        let inst = vk::Instance::new(&vk::InstanceCreateInfo {
+            application_info: Some(&vk::ApplicationInfo {
+                api_version: 1 << 22 | 2 << 12,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        if phy.api_version() < 1 << 22 | 2 << 12 {
+            return Ok(());
+        }
+        assert!(phy.properties2()?.vulkan_1_2.is_some());
+        let supported = phy.features2()?;
+        let timeline = supported.vulkan_1_2.timeline_semaphore;
+        let (dev, _) = vk::Device::new(
+            &phy,
+            &vk::DeviceCreateInfo {
+                queue_create_infos: vk::slice(&[vk::DeviceQueueCreateInfo {
+                    queue_priorities: vk::slice(&[1.0]),
+                    ..Default::default()
+                }]),
+                next: (&vk::DeviceFeatures {
+                    vulkan_1_2: vk::PhysicalDeviceVulkan12Features {
+                        timeline_semaphore: timeline,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                    .into(),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(dev.enabled().vulkan_1_2.timeline_semaphore, timeline);
+        assert!(!dev.enabled().vulkan_1_3.dynamic_rendering.as_bool());
+        Ok(())
+    }
+
+    #[test]
+    fn features_1_1() -> vk::Result<()> {
+        let inst = vk::Instance::new(&vk::InstanceCreateInfo {
+            application_info: Some(&vk::ApplicationInfo {
+                api_version: 1 << 22 | 1 << 12,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        if phy.api_version() < 1 << 22 | 1 << 12 {
+            return Ok(());
+        }
+        let draw_parameters =
+            phy.features2()?.vulkan_1_1.shader_draw_parameters;
+        let (dev, _) = vk::Device::new(
+            &phy,
+            &vk::DeviceCreateInfo {
+                queue_create_infos: vk::slice(&[vk::DeviceQueueCreateInfo {
+                    queue_priorities: vk::slice(&[1.0]),
+                    ..Default::default()
+                }]),
+                next: (&vk::DeviceFeatures {
+                    vulkan_1_1: vk::PhysicalDeviceVulkan11Features {
+                        shader_draw_parameters: draw_parameters,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                    .into(),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            dev.enabled().vulkan_1_1.shader_draw_parameters,
+            draw_parameters
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn features2_requires_instance_support() -> vk::Result<()> {
+        let inst = vk::Instance::new(&Default::default())?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        assert_eq!(
+            phy.features2().unwrap_err(),
+            vk::Error::ExtensionNotPresent
+        );
+        assert_eq!(
+            phy.properties2().unwrap_err(),
+            vk::Error::ExtensionNotPresent
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn heap_budget() -> vk::Result<()> {
+        let inst = vk::Instance::new(&Default::default())?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        assert_eq!(phy.heap_budget(), Err(vk::Error::ExtensionNotPresent));
+        assert_eq!(phy.heap_usage(), Err(vk::Error::ExtensionNotPresent));
+
+        let inst = vk::Instance::new(&vk::InstanceCreateInfo {
+            application_info: Some(&vk::ApplicationInfo {
+                api_version: 1 << 22 | 1 << 12,
+                ..Default::default()
+            }),
+            ..Default::default()
+        })?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        let supported = phy
+            .device_extension_properties()?
+            .iter()
+            .any(|ext| ext.extension_name.as_str() == "VK_EXT_memory_budget");
+        if !inst.has_properties2() || !supported {
+            assert_eq!(phy.heap_budget(), Err(vk::Error::ExtensionNotPresent));
+            assert_eq!(phy.heap_usage(), Err(vk::Error::ExtensionNotPresent));
+            return Ok(());
+        }
+        let heaps = phy.memory_properties().memory_heaps.len() as usize;
+        assert_eq!(phy.heap_budget()?.len(), heaps);
+        assert_eq!(phy.heap_usage()?.len(), heaps);
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_feature() -> vk::Result<()> {
+        let inst = vk::Instance::new(&Default::default())?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        let supported = phy.features().multi_draw_indirect.as_bool();
+        let result = vk::Device::new(
+            &phy,
+            &vk::DeviceCreateInfo {
+                queue_create_infos: vk::slice(&[vk::DeviceQueueCreateInfo {
+                    queue_priorities: vk::slice(&[1.0]),
+                    ..Default::default()
+                }]),
+                enabled_features: Some(&vk::PhysicalDeviceFeatures {
+                    multi_draw_indirect: vk::True,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        );
+        match result {
+            Ok((dev, _)) => {
+                assert!(supported);
+                assert!(dev.enabled().features.multi_draw_indirect.as_bool());
+            }
+            Err(err) => {
+                assert!(!supported);
+                match err {
+                    vk::Error::MissingFeatures(names) => assert_eq!(
+                        names.iter().collect::<Vec<_>>(),
+                        ["multi_draw_indirect"]
+                    ),
+                    _ => panic!("unexpected error {:?}", err),
+                }
+            }
+        }
+        Ok(())
+    }
+}
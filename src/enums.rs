@@ -11,17 +11,13 @@ use crate::types::{Extent2D, Extent3D};
 use bitflags::bitflags;
 
 #[repr(u32)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
 /// 32 bit bool
 pub enum Bool {
+    #[default]
     False = 0,
     True = 1,
 }
-impl Default for Bool {
-    fn default() -> Self {
-        Self::False
-    }
-}
 impl From<Bool> for bool {
     #[inline]
     fn from(b: Bool) -> Self {
@@ -128,6 +124,84 @@ bitflags! {
     pub struct DeviceCreateFlags: u32 {}
 }
 
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = crate::man_link!(VkPointClippingBehavior)]
+pub struct PointClippingBehavior(u32);
+impl PointClippingBehavior {
+    pub const ALL_CLIP_PLANES: Self = Self(0);
+    pub const USER_CLIP_PLANES_ONLY: Self = Self(1);
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkSubgroupFeatureFlagBits)]
+    pub struct SubgroupFeatureFlags: u32 {
+        const BASIC = 0x01;
+        const VOTE = 0x02;
+        const ARITHMETIC = 0x04;
+        const BALLOT = 0x08;
+        const SHUFFLE = 0x10;
+        const SHUFFLE_RELATIVE = 0x20;
+        const CLUSTERED = 0x40;
+        const QUAD = 0x80;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = crate::man_link!(VkDriverId)]
+pub struct DriverId(u32);
+impl DriverId {
+    pub const AMD_PROPRIETARY: Self = Self(1);
+    pub const AMD_OPEN_SOURCE: Self = Self(2);
+    pub const MESA_RADV: Self = Self(3);
+    pub const NVIDIA_PROPRIETARY: Self = Self(4);
+    pub const INTEL_PROPRIETARY_WINDOWS: Self = Self(5);
+    pub const INTEL_OPEN_SOURCE_MESA: Self = Self(6);
+    pub const IMAGINATION_PROPRIETARY: Self = Self(7);
+    pub const QUALCOMM_PROPRIETARY: Self = Self(8);
+    pub const ARM_PROPRIETARY: Self = Self(9);
+    pub const GOOGLE_SWIFTSHADER: Self = Self(10);
+    pub const GGP_PROPRIETARY: Self = Self(11);
+    pub const BROADCOM_PROPRIETARY: Self = Self(12);
+    pub const MESA_LLVMPIPE: Self = Self(13);
+    pub const MOLTENVK: Self = Self(14);
+    pub const COREAVI_PROPRIETARY: Self = Self(15);
+    pub const JUICE_PROPRIETARY: Self = Self(16);
+    pub const VERISILICON_PROPRIETARY: Self = Self(17);
+    pub const MESA_TURNIP: Self = Self(18);
+    pub const MESA_V3DV: Self = Self(19);
+    pub const MESA_PANVK: Self = Self(20);
+    pub const SAMSUNG_PROPRIETARY: Self = Self(21);
+    pub const MESA_VENUS: Self = Self(22);
+    pub const MESA_DOZEN: Self = Self(23);
+    pub const MESA_NVK: Self = Self(24);
+}
+
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = crate::man_link!(VkShaderFloatControlsIndependence)]
+pub struct ShaderFloatControlsIndependence(u32);
+impl ShaderFloatControlsIndependence {
+    pub const _32_BIT_ONLY: Self = Self(0);
+    pub const ALL: Self = Self(1);
+    pub const NONE: Self = Self(2);
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkResolveModeFlagBits)]
+    pub struct ResolveModeFlags: u32 {
+        const SAMPLE_ZERO = 0x01;
+        const AVERAGE = 0x02;
+        const MIN = 0x04;
+        const MAX = 0x08;
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Default)]
@@ -251,6 +325,76 @@ bitflags! {
     pub struct SemaphoreCreateFlags: u32 {}
 }
 
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[doc = crate::man_link!(VkSemaphoreType)]
+pub struct SemaphoreType(u32);
+impl SemaphoreType {
+    pub const BINARY: Self = Self(0);
+    pub const TIMELINE: Self = Self(1);
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkSemaphoreWaitFlagBits)]
+    pub struct SemaphoreWaitFlags: u32 {
+        const ANY = 0x1;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkExternalSemaphoreHandleTypeFlagBits)]
+    pub struct ExternalSemaphoreHandleTypeFlags: u32 {
+        const OPAQUE_FD = 0x1;
+        const OPAQUE_WIN32 = 0x2;
+        const OPAQUE_WIN32_KMT = 0x4;
+        const D3D12_FENCE = 0x8;
+        const SYNC_FD = 0x10;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkSemaphoreImportFlagBits)]
+    pub struct SemaphoreImportFlags: u32 {
+        const TEMPORARY = 0x1;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkExternalFenceHandleTypeFlagBits)]
+    pub struct ExternalFenceHandleTypeFlags: u32 {
+        const OPAQUE_FD = 0x1;
+        const OPAQUE_WIN32 = 0x2;
+        const OPAQUE_WIN32_KMT = 0x4;
+        const SYNC_FD = 0x8;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkFenceImportFlagBits)]
+    pub struct FenceImportFlags: u32 {
+        const TEMPORARY = 0x1;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkEventCreateFlagBits)]
+    pub struct EventCreateFlags: u32 {
+        const DEVICE_ONLY = 0x1;
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Default)]
@@ -379,6 +523,82 @@ bitflags! {
     pub struct MetalSurfaceCreateFlagsEXT: u32 {}
 }
 
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    /// Reserved
+    pub struct DebugUtilsMessengerCreateFlagsEXT: u32 {}
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    /// Reserved
+    pub struct DebugUtilsMessengerCallbackDataFlagsEXT: u32 {}
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkDebugUtilsMessageSeverityFlagBitsEXT)]
+    pub struct DebugUtilsMessageSeverityFlagsEXT: u32 {
+        const VERBOSE = 0x0001;
+        const INFO = 0x0010;
+        const WARNING = 0x0100;
+        const ERROR = 0x1000;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkDebugUtilsMessageTypeFlagBitsEXT)]
+    pub struct DebugUtilsMessageTypeFlagsEXT: u32 {
+        const GENERAL = 0x1;
+        const VALIDATION = 0x2;
+        const PERFORMANCE = 0x4;
+        const DEVICE_ADDRESS_BINDING = 0x8;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[doc = crate::man_link!(VkObjectType)]
+pub struct ObjectType(u32);
+impl ObjectType {
+    pub const UNKNOWN: Self = Self(0);
+    pub const INSTANCE: Self = Self(1);
+    pub const PHYSICAL_DEVICE: Self = Self(2);
+    pub const DEVICE: Self = Self(3);
+    pub const QUEUE: Self = Self(4);
+    pub const SEMAPHORE: Self = Self(5);
+    pub const COMMAND_BUFFER: Self = Self(6);
+    pub const FENCE: Self = Self(7);
+    pub const DEVICE_MEMORY: Self = Self(8);
+    pub const BUFFER: Self = Self(9);
+    pub const IMAGE: Self = Self(10);
+    pub const EVENT: Self = Self(11);
+    pub const QUERY_POOL: Self = Self(12);
+    pub const BUFFER_VIEW: Self = Self(13);
+    pub const IMAGE_VIEW: Self = Self(14);
+    pub const SHADER_MODULE: Self = Self(15);
+    pub const PIPELINE_CACHE: Self = Self(16);
+    pub const PIPELINE_LAYOUT: Self = Self(17);
+    pub const RENDER_PASS: Self = Self(18);
+    pub const PIPELINE: Self = Self(19);
+    pub const DESCRIPTOR_SET_LAYOUT: Self = Self(20);
+    pub const SAMPLER: Self = Self(21);
+    pub const DESCRIPTOR_POOL: Self = Self(22);
+    pub const DESCRIPTOR_SET: Self = Self(23);
+    pub const FRAMEBUFFER: Self = Self(24);
+    pub const COMMAND_POOL: Self = Self(25);
+    pub const SAMPLER_YCBCR_CONVERSION: Self = Self(1000156000);
+    pub const DESCRIPTOR_UPDATE_TEMPLATE: Self = Self(1000085000);
+    pub const SURFACE_KHR: Self = Self(1000000000);
+    pub const SWAPCHAIN_KHR: Self = Self(1000001000);
+    pub const DEBUG_UTILS_MESSENGER_EXT: Self = Self(1000128000);
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Default)]
@@ -502,6 +722,30 @@ bitflags! {
     }
 }
 
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkFormatFeatureFlagBits)]
+    pub struct FormatFeatureFlags: u32 {
+        const SAMPLED_IMAGE = 0x1;
+        const STORAGE_IMAGE = 0x2;
+        const STORAGE_IMAGE_ATOMIC = 0x4;
+        const UNIFORM_TEXEL_BUFFER = 0x8;
+        const STORAGE_TEXEL_BUFFER = 0x10;
+        const STORAGE_TEXEL_BUFFER_ATOMIC = 0x20;
+        const VERTEX_BUFFER = 0x40;
+        const COLOR_ATTACHMENT = 0x80;
+        const COLOR_ATTACHMENT_BLEND = 0x100;
+        const DEPTH_STENCIL_ATTACHMENT = 0x200;
+        const BLIT_SRC = 0x400;
+        const BLIT_DST = 0x800;
+        const SAMPLED_IMAGE_FILTER_LINEAR = 0x1000;
+        const TRANSFER_SRC = 0x4000;
+        const TRANSFER_DST = 0x8000;
+        const SAMPLED_IMAGE_FILTER_MINMAX = 0x10000;
+    }
+}
+
 impl ImageUsageFlags {
     /// Does the usage support arbitrary shader access?
     pub fn indexable(self) -> bool {
@@ -776,200 +1020,13 @@ impl Format {
     pub const ASTC_12X12_SFLOAT_BLOCK: Self = Self(1000066013);
 }
 
-impl Format {
-    /// Number of bytes per block
-    pub fn bytes(self) -> u64 {
-        match self {
-            Self::R8_SINT => 1,
-            Self::R8_SNORM => 1,
-            Self::R8_SRGB => 1,
-            Self::R8_UINT => 1,
-            Self::R8_UNORM => 1,
-
-            Self::R8G8_SINT => 2,
-            Self::R8G8_SNORM => 2,
-            Self::R8G8_UINT => 2,
-            Self::R8G8_UNORM => 2,
-            Self::R16_SFLOAT => 2,
-            Self::R16_SINT => 2,
-            Self::R16_SNORM => 2,
-            Self::R16_UINT => 2,
-            Self::R16_UNORM => 2,
-            Self::D16_UNORM => 2,
-            Self::A1R5G5B5_UNORM_PACK16 => 2,
-            Self::B4G4R4A4_UNORM_PACK16 => 2,
-            Self::B5G5R5A1_UNORM_PACK16 => 2,
-            Self::B5G6R5_UNORM_PACK16 => 2,
-            Self::R4G4B4A4_UNORM_PACK16 => 2,
-            Self::R5G5B5A1_UNORM_PACK16 => 2,
-            Self::R5G6B5_UNORM_PACK16 => 2,
-
-            Self::B8G8R8A8_SINT => 4,
-            Self::B8G8R8A8_SNORM => 4,
-            Self::B8G8R8A8_SRGB => 4,
-            Self::B8G8R8A8_UINT => 4,
-            Self::B8G8R8A8_UNORM => 4,
-            Self::R8G8B8A8_SINT => 4,
-            Self::R8G8B8A8_SNORM => 4,
-            Self::R8G8B8A8_SRGB => 4,
-            Self::R8G8B8A8_UINT => 4,
-            Self::R8G8B8A8_UNORM => 4,
-            Self::R16G16_SFLOAT => 4,
-            Self::R16G16_SINT => 4,
-            Self::R16G16_SNORM => 4,
-            Self::R16G16_UINT => 4,
-            Self::R16G16_UNORM => 4,
-            Self::R32_SFLOAT => 4,
-            Self::R32_SINT => 4,
-            Self::R32_UINT => 4,
-            Self::A2B10G10R10_UINT_PACK32 => 4,
-            Self::A2B10G10R10_UNORM_PACK32 => 4,
-            Self::A2R10G10B10_UINT_PACK32 => 4,
-            Self::A2R10G10B10_UNORM_PACK32 => 4,
-            Self::A8B8G8R8_SINT_PACK32 => 4,
-            Self::A8B8G8R8_SNORM_PACK32 => 4,
-            Self::A8B8G8R8_SRGB_PACK32 => 4,
-            Self::A8B8G8R8_UINT_PACK32 => 4,
-            Self::A8B8G8R8_UNORM_PACK32 => 4,
-            Self::B10G11R11_UFLOAT_PACK32 => 4,
-            Self::E5B9G9R9_UFLOAT_PACK32 => 4,
-            Self::D24_UNORM_S8_UINT => 4,
-            Self::D32_SFLOAT => 4,
-            Self::X8_D24_UNORM_PACK32 => 4,
-
-            Self::R16G16B16A16_SFLOAT => 8,
-            Self::R16G16B16A16_SINT => 8,
-            Self::R16G16B16A16_SNORM => 8,
-            Self::R16G16B16A16_UINT => 8,
-            Self::R16G16B16A16_UNORM => 8,
-            Self::R32G32_SFLOAT => 8,
-            Self::R32G32_SINT => 8,
-            Self::R32G32_UINT => 8,
-
-            Self::R32G32B32_SFLOAT => 12,
-            Self::R32G32B32_SINT => 12,
-            Self::R32G32B32_UINT => 12,
-
-            Self::R32G32B32A32_SFLOAT => 16,
-            Self::R32G32B32A32_SINT => 16,
-            Self::R32G32B32A32_UINT => 16,
-
-            Self::B8G8R8G8_422_UNORM => 4,
-            Self::G8B8G8R8_422_UNORM => 4,
-
-            Self::EAC_R11_SNORM_BLOCK => 8,
-            Self::EAC_R11_UNORM_BLOCK => 8,
-            Self::EAC_R11G11_SNORM_BLOCK => 16,
-            Self::EAC_R11G11_UNORM_BLOCK => 16,
-            Self::ETC2_R8G8B8_SRGB_BLOCK => 8,
-            Self::ETC2_R8G8B8_UNORM_BLOCK => 8,
-            Self::ETC2_R8G8B8A1_SRGB_BLOCK => 8,
-            Self::ETC2_R8G8B8A1_UNORM_BLOCK => 8,
-            Self::ETC2_R8G8B8A8_SRGB_BLOCK => 16,
-            Self::ETC2_R8G8B8A8_UNORM_BLOCK => 16,
-
-            _ => panic!("Unimplemented format"),
-        }
-    }
-    /// Number of texels per block
-    pub fn texels(self) -> Extent2D {
-        const ONE: Extent2D = Extent2D { width: 1, height: 1 };
-        const TWO_ONE: Extent2D = Extent2D { width: 2, height: 1 };
-        const FOUR_FOUR: Extent2D = Extent2D { width: 4, height: 4 };
-        match self {
-            Self::R8_SINT => ONE,
-            Self::R8_SNORM => ONE,
-            Self::R8_SRGB => ONE,
-            Self::R8_UINT => ONE,
-            Self::R8_UNORM => ONE,
-            Self::R8G8_SINT => ONE,
-            Self::R8G8_SNORM => ONE,
-            Self::R8G8_UINT => ONE,
-            Self::R8G8_UNORM => ONE,
-            Self::R16_SFLOAT => ONE,
-            Self::R16_SINT => ONE,
-            Self::R16_SNORM => ONE,
-            Self::R16_UINT => ONE,
-            Self::R16_UNORM => ONE,
-            Self::D16_UNORM => ONE,
-            Self::A1R5G5B5_UNORM_PACK16 => ONE,
-            Self::B4G4R4A4_UNORM_PACK16 => ONE,
-            Self::B5G5R5A1_UNORM_PACK16 => ONE,
-            Self::B5G6R5_UNORM_PACK16 => ONE,
-            Self::R4G4B4A4_UNORM_PACK16 => ONE,
-            Self::R5G5B5A1_UNORM_PACK16 => ONE,
-            Self::R5G6B5_UNORM_PACK16 => ONE,
-            Self::B8G8R8A8_SINT => ONE,
-            Self::B8G8R8A8_SNORM => ONE,
-            Self::B8G8R8A8_SRGB => ONE,
-            Self::B8G8R8A8_UINT => ONE,
-            Self::B8G8R8A8_UNORM => ONE,
-            Self::R8G8B8A8_SINT => ONE,
-            Self::R8G8B8A8_SNORM => ONE,
-            Self::R8G8B8A8_SRGB => ONE,
-            Self::R8G8B8A8_UINT => ONE,
-            Self::R8G8B8A8_UNORM => ONE,
-            Self::R16G16_SFLOAT => ONE,
-            Self::R16G16_SINT => ONE,
-            Self::R16G16_SNORM => ONE,
-            Self::R16G16_UINT => ONE,
-            Self::R16G16_UNORM => ONE,
-            Self::R32_SFLOAT => ONE,
-            Self::R32_SINT => ONE,
-            Self::R32_UINT => ONE,
-            Self::A2B10G10R10_UINT_PACK32 => ONE,
-            Self::A2B10G10R10_UNORM_PACK32 => ONE,
-            Self::A2R10G10B10_UINT_PACK32 => ONE,
-            Self::A2R10G10B10_UNORM_PACK32 => ONE,
-            Self::A8B8G8R8_SINT_PACK32 => ONE,
-            Self::A8B8G8R8_SNORM_PACK32 => ONE,
-            Self::A8B8G8R8_SRGB_PACK32 => ONE,
-            Self::A8B8G8R8_UINT_PACK32 => ONE,
-            Self::A8B8G8R8_UNORM_PACK32 => ONE,
-            Self::B10G11R11_UFLOAT_PACK32 => ONE,
-            Self::E5B9G9R9_UFLOAT_PACK32 => ONE,
-            Self::D24_UNORM_S8_UINT => ONE,
-            Self::D32_SFLOAT => ONE,
-            Self::X8_D24_UNORM_PACK32 => ONE,
-            Self::R16G16B16A16_SFLOAT => ONE,
-            Self::R16G16B16A16_SINT => ONE,
-            Self::R16G16B16A16_SNORM => ONE,
-            Self::R16G16B16A16_UINT => ONE,
-            Self::R16G16B16A16_UNORM => ONE,
-            Self::R32G32_SFLOAT => ONE,
-            Self::R32G32_SINT => ONE,
-            Self::R32G32_UINT => ONE,
-            Self::R32G32B32_SFLOAT => ONE,
-            Self::R32G32B32_SINT => ONE,
-            Self::R32G32B32_UINT => ONE,
-            Self::R32G32B32A32_SFLOAT => ONE,
-            Self::R32G32B32A32_SINT => ONE,
-            Self::R32G32B32A32_UINT => ONE,
-
-            Self::B8G8R8G8_422_UNORM => TWO_ONE,
-            Self::G8B8G8R8_422_UNORM => TWO_ONE,
-            Self::EAC_R11_SNORM_BLOCK => FOUR_FOUR,
-            Self::EAC_R11_UNORM_BLOCK => FOUR_FOUR,
-            Self::EAC_R11G11_SNORM_BLOCK => FOUR_FOUR,
-            Self::EAC_R11G11_UNORM_BLOCK => FOUR_FOUR,
-            Self::ETC2_R8G8B8_SRGB_BLOCK => FOUR_FOUR,
-            Self::ETC2_R8G8B8_UNORM_BLOCK => FOUR_FOUR,
-            Self::ETC2_R8G8B8A1_SRGB_BLOCK => FOUR_FOUR,
-            Self::ETC2_R8G8B8A1_UNORM_BLOCK => FOUR_FOUR,
-            Self::ETC2_R8G8B8A8_SRGB_BLOCK => FOUR_FOUR,
-            Self::ETC2_R8G8B8A8_UNORM_BLOCK => FOUR_FOUR,
-            _ => panic!("Unimplemented format"),
-        }
-    }
-}
-
 /// Bytes in image size and format. Returns None on overflow.
 pub fn image_byte_size_2d(format: Format, extent: Extent2D) -> Option<u64> {
-    let block = format.texels();
+    let block = format.block_extent();
     let w = (extent.width.checked_add(block.width)? - 1) / block.width;
     let h = (extent.height.checked_add(block.height)? - 1) / block.height;
     let blocks = (w as u64).checked_mul(h as u64)?;
-    blocks.checked_mul(format.bytes())
+    blocks.checked_mul(format.block_size() as u64)
 }
 /// Bytes in image size and format. Returns None on overflow.
 pub fn image_byte_size_3d(format: Format, extent: Extent3D) -> Option<u64> {
@@ -1053,15 +1110,58 @@ impl CommandBufferLevel {
 bitflags! {
     #[repr(transparent)]
     #[derive(Default)]
-    /// Reserved
-    pub struct QueryControlFlags: u32 {}
+    #[doc = crate::man_link!(VkQueryControlFlagBits)]
+    pub struct QueryControlFlags: u32 {
+        const PRECISE = 0x1;
+    }
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkQueryPipelineStatisticFlagBits)]
+    pub struct QueryPipelineStatisticFlags: u32 {
+        const INPUT_ASSEMBLY_VERTICES = 0x001;
+        const INPUT_ASSEMBLY_PRIMITIVES = 0x002;
+        const VERTEX_SHADER_INVOCATIONS = 0x004;
+        const GEOMETRY_SHADER_INVOCATIONS = 0x008;
+        const GEOMETRY_SHADER_PRIMITIVES = 0x010;
+        const CLIPPING_INVOCATIONS = 0x020;
+        const CLIPPING_PRIMITIVES = 0x040;
+        const FRAGMENT_SHADER_INVOCATIONS = 0x080;
+        const TESSELLATION_CONTROL_SHADER_PATCHES = 0x100;
+        const TESSELLATION_EVALUATION_SHADER_INVOCATIONS = 0x200;
+        const COMPUTE_SHADER_INVOCATIONS = 0x400;
+    }
 }
 
 bitflags! {
     #[repr(transparent)]
     #[derive(Default)]
     /// Reserved
-    pub struct QueryPipelineStatisticFlags: u32 {}
+    pub struct QueryPoolCreateFlags: u32 {}
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[doc = crate::man_link!(VkQueryType)]
+pub struct QueryType(u32);
+impl QueryType {
+    pub const OCCLUSION: Self = Self(0);
+    pub const PIPELINE_STATISTICS: Self = Self(1);
+    pub const TIMESTAMP: Self = Self(2);
+}
+
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkQueryResultFlagBits)]
+    pub struct QueryResultFlags: u32 {
+        const _64 = 0x1;
+        const WAIT = 0x2;
+        const WITH_AVAILABILITY = 0x4;
+        const PARTIAL = 0x8;
+    }
 }
 
 bitflags! {
@@ -1666,6 +1766,24 @@ bitflags! {
     }
 }
 
+bitflags! {
+    #[repr(transparent)]
+    #[derive(Default)]
+    #[doc = crate::man_link!(VkExternalMemoryHandleTypeFlagBits)]
+    pub struct ExternalMemoryHandleTypeFlags: u32 {
+        const OPAQUE_FD = 0x1;
+        const OPAQUE_WIN32 = 0x2;
+        const OPAQUE_WIN32_KMT = 0x4;
+        const D3D11_TEXTURE = 0x8;
+        const D3D11_TEXTURE_KMT = 0x10;
+        const D3D12_HEAP = 0x20;
+        const D3D12_RESOURCE = 0x40;
+        const HOST_ALLOCATION_EXT = 0x80;
+        const HOST_MAPPED_FOREIGN_MEMORY_EXT = 0x100;
+        const DMA_BUF_EXT = 0x200;
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Default)]
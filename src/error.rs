@@ -6,6 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::physical_device::FeatureNames;
 use crate::types::VkError;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -25,6 +26,9 @@ pub enum Error {
     SynchronizationError,
     /// The arguments exceed the limits of the device.
     LimitExceeded,
+    /// The features are required but are not enabled on the device, or are
+    /// enabled but not supported by the physical device.
+    MissingFeatures(FeatureNames),
     #[doc = crate::man_link!(VkResult)]
     NotReady,
     #[doc = crate::man_link!(VkResult)]
@@ -44,6 +48,8 @@ pub enum Error {
     #[doc = crate::man_link!(VkResult)]
     IncompatibleDriver,
     #[doc = crate::man_link!(VkResult)]
+    FormatNotSupported,
+    #[doc = crate::man_link!(VkResult)]
     DeviceLost,
     #[doc = crate::man_link!(VkResult)]
     SurfaceLostKHR,
@@ -77,6 +83,7 @@ impl From<VkError> for Error {
             -7 => Self::ExtensionNotPresent,
             -8 => Self::FeatureNotPresent,
             -9 => Self::IncompatibleDriver,
+            -11 => Self::FormatNotSupported,
             -1000000000 => Self::SurfaceLostKHR,
             -1000069000 => Self::OutOfPoolMemory,
             1000001003 => Self::SuboptimalHKR,
@@ -0,0 +1,139 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::device::Device;
+use crate::error::{Error, Result};
+use crate::types::*;
+
+/// An
+#[doc = crate::spec_link!("event", "7", "synchronization-events")]
+///
+/// Command buffers which use the event hold a reference to it, so it is not
+/// destroyed until they are no longer in use.
+#[derive(Debug)]
+pub struct Event {
+    pub(crate) inner: Arc<EventRAII>,
+}
+
+#[derive(Debug)]
+pub(crate) struct EventRAII {
+    handle: Handle<VkEvent>,
+    device: Arc<Device>,
+}
+
+impl Event {
+    #[doc = crate::man_link!(vkCreateEvent)]
+    pub fn new(device: &Arc<Device>, info: &EventCreateInfo) -> Result<Self> {
+        let mut handle = None;
+        unsafe {
+            (device.fun.create_event)(
+                device.handle(),
+                info,
+                None,
+                &mut handle,
+            )?;
+        }
+        Ok(Self {
+            inner: Arc::new(EventRAII {
+                handle: handle.unwrap(),
+                device: device.clone(),
+            }),
+        })
+    }
+}
+
+impl Drop for EventRAII {
+    fn drop(&mut self) {
+        unsafe {
+            (self.device.fun.destroy_event)(
+                self.device.handle(),
+                self.handle.borrow_mut(),
+                None,
+            )
+        }
+    }
+}
+
+impl Event {
+    /// Borrows the inner Vulkan handle.
+    pub fn handle(&self) -> Ref<'_, VkEvent> {
+        self.inner.handle.borrow()
+    }
+    /// Borrows the inner Vulkan handle.
+    pub fn mut_handle(&mut self) -> Mut<'_, VkEvent> {
+        // Safe because the outer structure is mutably borrowed, and handle is
+        // private.
+        unsafe { self.inner.handle.borrow_mut_unchecked() }
+    }
+    /// Returns the associated device.
+    pub fn device(&self) -> &Arc<Device> {
+        &self.inner.device
+    }
+
+    /// Returns true if the event is signaled.
+    #[doc = crate::man_link!(vkGetEventStatus)]
+    pub fn status(&self) -> Result<bool> {
+        const EVENT_SET: i32 = 3;
+        const EVENT_RESET: i32 = 4;
+        let result = unsafe {
+            (self.inner.device.fun.get_event_status)(
+                self.inner.device.handle(),
+                self.handle(),
+            )
+        };
+        match result {
+            Err(err) if err.0.get() == EVENT_SET => Ok(true),
+            Err(err) if err.0.get() == EVENT_RESET => Ok(false),
+            Err(err) => Err(err.into()),
+            Ok(()) => Err(Error::Other),
+        }
+    }
+    /// Sets the event from the host.
+    #[doc = crate::man_link!(vkSetEvent)]
+    pub fn set(&mut self) -> Result<()> {
+        let inner = &*self.inner;
+        // Safe because self is mutably borrowed.
+        unsafe {
+            (inner.device.fun.set_event)(
+                inner.device.handle(),
+                inner.handle.borrow_mut_unchecked(),
+            )?
+        }
+        Ok(())
+    }
+    /// Resets the event from the host.
+    #[doc = crate::man_link!(vkResetEvent)]
+    pub fn reset(&mut self) -> Result<()> {
+        let inner = &*self.inner;
+        // Safe because self is mutably borrowed.
+        unsafe {
+            (inner.device.fun.reset_event)(
+                inner.device.handle(),
+                inner.handle.borrow_mut_unchecked(),
+            )?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn host_set_reset() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let mut event = vk::Event::new(&dev, &Default::default())?;
+        assert!(!event.status()?);
+        event.set()?;
+        assert!(event.status()?);
+        event.reset()?;
+        assert!(!event.status()?);
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@
 
 use crate::ffi::Str;
 
+mod ext_debug_utils;
 mod ext_metal_surface;
 mod khr_surface;
 mod khr_xlib_surface;
@@ -17,6 +18,9 @@ mod khr_wayland_surface;
 mod khr_win32_surface;
 pub(crate) mod khr_swapchain;
 
+pub use ext_debug_utils::{
+    DebugCallback, DebugUtilsEXT, DebugUtilsMessengerEXT,
+};
 pub use ext_metal_surface::EXTMetalSurface;
 pub use khr_surface::SurfaceKHR;
 pub use khr_swapchain::{SwapchainCreateInfoKHR, SwapchainKHR};
@@ -47,9 +51,15 @@ pub const ANDROID_SURFACE: Str<'static> =
 /// VK_EXT_metal_surface instance extension name
 pub const METAL_SURFACE: Str<'static> =
     unsafe { Str::new_unchecked(b"VK_EXT_metal_surface\0") };
+/// VK_EXT_debug_utils instance extension name
+pub const DEBUG_UTILS: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_EXT_debug_utils\0") };
 /// VK_KHR_get_physical_device_properties2 instance extension name
 pub const GET_PHYSICAL_DEVICE_PROPERTIES2: Str<'static> =
     unsafe { Str::new_unchecked(b"VK_KHR_get_physical_device_properties2\0") };
+/// VK_KHR_external_memory_capabilities instance extension name
+pub const EXTERNAL_MEMORY_CAPABILITIES: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_KHR_external_memory_capabilities\0") };
 
 // Device level extensions
 
@@ -59,3 +69,21 @@ pub const PORTABILITY_SUBSET: Str<'static> =
 /// VK_KHR_swapchain device extension name
 pub const SWAPCHAIN: Str<'static> =
     unsafe { Str::new_unchecked(b"VK_KHR_swapchain\0") };
+/// VK_KHR_external_memory device extension name
+pub const EXTERNAL_MEMORY: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_KHR_external_memory\0") };
+/// VK_KHR_external_memory_fd device extension name
+pub const EXTERNAL_MEMORY_FD: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_KHR_external_memory_fd\0") };
+/// VK_EXT_external_memory_host device extension name
+pub const EXTERNAL_MEMORY_HOST: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_EXT_external_memory_host\0") };
+/// VK_KHR_external_semaphore_fd device extension name
+pub const EXTERNAL_SEMAPHORE_FD: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_KHR_external_semaphore_fd\0") };
+/// VK_KHR_external_fence_fd device extension name
+pub const EXTERNAL_FENCE_FD: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_KHR_external_fence_fd\0") };
+/// VK_EXT_memory_priority device extension name
+pub const MEMORY_PRIORITY: Str<'static> =
+    unsafe { Str::new_unchecked(b"VK_EXT_memory_priority\0") };
@@ -0,0 +1,321 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem::transmute;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::enums::*;
+use crate::error::Result;
+use crate::ffi::{c_void, NonNull};
+use crate::instance::Instance;
+use crate::types::*;
+
+/// A function which receives debug messages, such as those from the
+/// validation layers.
+pub struct DebugCallback(
+    #[allow(clippy::type_complexity)]
+    Box<
+        dyn Fn(
+                DebugUtilsMessageSeverityFlagsEXT,
+                DebugUtilsMessageTypeFlagsEXT,
+                &DebugUtilsMessengerCallbackDataEXT,
+            ) + Send
+            + Sync,
+    >,
+);
+
+impl std::fmt::Debug for DebugCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugCallback").finish()
+    }
+}
+
+impl DebugCallback {
+    /// Calls `f` for each message. Panics in `f` are caught and do not
+    /// propagate into the Vulkan implementation.
+    pub fn new(
+        f: impl Fn(
+                DebugUtilsMessageSeverityFlagsEXT,
+                DebugUtilsMessageTypeFlagsEXT,
+                &DebugUtilsMessengerCallbackDataEXT,
+            ) + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self(Box::new(f))
+    }
+
+    /// Forwards messages to the [`log`] crate, with the target "vulkan".
+    /// Errors, warnings, and info messages are logged at the corresponding
+    /// level, and verbose messages are logged at the debug level.
+    #[cfg(feature = "log")]
+    pub fn log() -> Self {
+        Self::new(|severity, message_type, data| {
+            let level = if severity
+                .contains(DebugUtilsMessageSeverityFlagsEXT::ERROR)
+            {
+                log::Level::Error
+            } else if severity
+                .contains(DebugUtilsMessageSeverityFlagsEXT::WARNING)
+            {
+                log::Level::Warn
+            } else if severity.contains(DebugUtilsMessageSeverityFlagsEXT::INFO)
+            {
+                log::Level::Info
+            } else {
+                log::Level::Debug
+            };
+            log::log!(
+                target: "vulkan",
+                level,
+                "[{:?}] {}",
+                message_type,
+                data.message.map_or("", |m| m.as_str())
+            );
+        })
+    }
+}
+
+unsafe extern "system" fn debug_callback(
+    severity: DebugUtilsMessageSeverityFlagsEXT,
+    message_type: DebugUtilsMessageTypeFlagsEXT,
+    data: &DebugUtilsMessengerCallbackDataEXT, callback: &DebugCallback,
+) -> Bool {
+    // Unwinding into the implementation is undefined behavior. The panic
+    // message has already been printed by the panic hook.
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        (callback.0)(severity, message_type, data)
+    }));
+    Bool::False
+}
+
+impl DebugUtilsMessengerCreateInfoEXT {
+    /// Create info for a messenger that calls `callback` for messages with
+    /// any of the given severities and types.
+    pub fn new(
+        message_severity: DebugUtilsMessageSeverityFlagsEXT,
+        message_type: DebugUtilsMessageTypeFlagsEXT,
+        callback: Arc<DebugCallback>,
+    ) -> Self {
+        Self {
+            stype: Default::default(),
+            next: Default::default(),
+            flags: Default::default(),
+            message_severity,
+            message_type,
+            pfn_user_callback: debug_callback,
+            user_data: NonNull::from(&*callback),
+            callback,
+        }
+    }
+}
+
+/// An EXT_debug_utils extension object.
+pub struct DebugUtilsEXT {
+    fun: DebugUtilsFn,
+    instance: Arc<Instance>,
+}
+
+impl DebugUtilsEXT {
+    /// Creates a [`DebugUtilsEXT`] extension object. Panics if the extension
+    /// functions can't be loaded.
+    pub fn new(instance: &Arc<Instance>) -> Self {
+        Self { fun: DebugUtilsFn::new(instance), instance: instance.clone() }
+    }
+
+    /// Creates a messenger which calls `callback` for messages with any of the
+    /// given severities and types, until it is dropped.
+    #[doc = crate::man_link!(vkCreateDebugUtilsMessengerEXT)]
+    pub fn create_messenger(
+        &self, message_severity: DebugUtilsMessageSeverityFlagsEXT,
+        message_type: DebugUtilsMessageTypeFlagsEXT, callback: DebugCallback,
+    ) -> Result<DebugUtilsMessengerEXT> {
+        let info = DebugUtilsMessengerCreateInfoEXT::new(
+            message_severity,
+            message_type,
+            Arc::new(callback),
+        );
+        let mut handle = None;
+        unsafe {
+            (self.fun.create_debug_utils_messenger_ext)(
+                self.instance.handle(),
+                &info,
+                None,
+                &mut handle,
+            )?;
+        }
+        Ok(DebugUtilsMessengerEXT {
+            handle: handle.unwrap(),
+            destroy_debug_utils_messenger_ext: self
+                .fun
+                .destroy_debug_utils_messenger_ext,
+            _callback: info.callback,
+            instance: self.instance.clone(),
+        })
+    }
+}
+
+/// A
+#[doc = crate::spec_link!("debug messenger", "50", "debugging-debug-messengers")]
+/// The callback is called until the messenger is dropped.
+pub struct DebugUtilsMessengerEXT {
+    handle: Handle<VkDebugUtilsMessengerEXT>,
+    destroy_debug_utils_messenger_ext: unsafe extern "system" fn(
+        Ref<VkInstance>,
+        Mut<VkDebugUtilsMessengerEXT>,
+        Option<&'_ AllocationCallbacks>,
+    ),
+    _callback: Arc<DebugCallback>,
+    instance: Arc<Instance>,
+}
+
+impl std::fmt::Debug for DebugUtilsMessengerEXT {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DebugUtilsMessengerEXT")
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl Drop for DebugUtilsMessengerEXT {
+    fn drop(&mut self) {
+        unsafe {
+            (self.destroy_debug_utils_messenger_ext)(
+                self.instance.handle(),
+                self.handle.borrow_mut(),
+                None,
+            )
+        }
+    }
+}
+
+impl DebugUtilsMessengerEXT {
+    /// Borrows the inner Vulkan handle.
+    pub fn handle(&self) -> Ref<'_, VkDebugUtilsMessengerEXT> {
+        self.handle.borrow()
+    }
+}
+
+pub struct DebugUtilsFn {
+    pub create_debug_utils_messenger_ext: unsafe extern "system" fn(
+        Ref<VkInstance>,
+        &DebugUtilsMessengerCreateInfoEXT,
+        Option<&'_ AllocationCallbacks>,
+        &mut Option<Handle<VkDebugUtilsMessengerEXT>>,
+    )
+        -> VkResult,
+    pub destroy_debug_utils_messenger_ext: unsafe extern "system" fn(
+        Ref<VkInstance>,
+        Mut<VkDebugUtilsMessengerEXT>,
+        Option<&'_ AllocationCallbacks>,
+    ),
+}
+
+impl DebugUtilsFn {
+    pub fn new(inst: &Instance) -> Self {
+        unsafe {
+            Self {
+                create_debug_utils_messenger_ext: transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkInstance>,
+                        &DebugUtilsMessengerCreateInfoEXT,
+                        Option<&'_ AllocationCallbacks>,
+                        &mut Option<Handle<VkDebugUtilsMessengerEXT>>,
+                    ) -> VkResult,
+                >(
+                    inst.get_proc_addr("vkCreateDebugUtilsMessengerEXT\0"),
+                ),
+                destroy_debug_utils_messenger_ext: transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkInstance>,
+                        Mut<VkDebugUtilsMessengerEXT>,
+                        Option<&'_ AllocationCallbacks>,
+                    ),
+                >(
+                    inst.get_proc_addr("vkDestroyDebugUtilsMessengerEXT\0"),
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn messenger() -> vk::Result<()> {
+        if !vk::instance_extension_properties()?
+            .iter()
+            .any(|e| e.extension_name == vk::ext::DEBUG_UTILS)
+        {
+            return Ok(());
+        }
+        let callback =
+            Arc::new(vk::ext::DebugCallback::new(|_, _, _| panic!("caught")));
+        let inst = vk::Instance::new(&vk::InstanceCreateInfo {
+            next: vk::ExtensionChain::new(
+                &vk::DebugUtilsMessengerCreateInfoEXT::new(
+                    vk::DebugUtilsMessageSeverityFlagsEXT::all(),
+                    vk::DebugUtilsMessageTypeFlagsEXT::GENERAL
+                        | vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION,
+                    callback.clone(),
+                ),
+            ),
+            enabled_extension_names: vk::slice(&[vk::ext::DEBUG_UTILS]),
+            ..Default::default()
+        })?;
+        // The instance keeps the callback alive for vkDestroyInstance.
+        drop(callback);
+        let missing_message = Arc::new(AtomicBool::new(false));
+        let missing = missing_message.clone();
+        let messenger = vk::ext::DebugUtilsEXT::new(&inst).create_messenger(
+            vk::DebugUtilsMessageSeverityFlagsEXT::all(),
+            vk::DebugUtilsMessageTypeFlagsEXT::GENERAL
+                | vk::DebugUtilsMessageTypeFlagsEXT::VALIDATION,
+            vk::ext::DebugCallback::new(move |_, _, data| {
+                if data.message.is_none() {
+                    missing.store(true, Ordering::Relaxed);
+                }
+            }),
+        )?;
+        let _ = inst.enumerate_physical_devices()?;
+        drop(messenger);
+        assert!(!missing_message.load(Ordering::Relaxed));
+        Ok(())
+    }
+
+    #[test]
+    fn panicking_callback() {
+        let callback = vk::ext::DebugCallback::new(|_, _, _| panic!("caught"));
+        let data = vk::DebugUtilsMessengerCallbackDataEXT {
+            stype: Default::default(),
+            next: None,
+            flags: Default::default(),
+            message_id_name: None,
+            message_id_number: 0,
+            message: Some(vk::Str::new("message\0").unwrap()),
+            queue_labels: Default::default(),
+            cmd_buf_labels: Default::default(),
+            objects: Default::default(),
+        };
+        let result = unsafe {
+            super::debug_callback(
+                vk::DebugUtilsMessageSeverityFlagsEXT::ERROR,
+                vk::DebugUtilsMessageTypeFlagsEXT::GENERAL,
+                &data,
+                &callback,
+            )
+        };
+        assert_eq!(result, vk::False);
+    }
+}
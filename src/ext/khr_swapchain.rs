@@ -163,6 +163,13 @@ impl SwapchainKHR {
             device: device.clone(),
             _surface: surface.resource(),
         });
+        let image_flags =
+            if info.flags.contains(SwapchainCreateFlagsKHR::MUTABLE_FORMAT) {
+                ImageCreateFlags::MUTABLE_FORMAT
+                    | ImageCreateFlags::EXTENDED_USAGE
+            } else {
+                ImageCreateFlags::empty()
+            };
         let images = images
             .into_iter()
             .map(|handle| {
@@ -175,6 +182,7 @@ impl SwapchainKHR {
                         info.image_extent.into(),
                         info.image_array_layers,
                         info.image_usage,
+                        image_flags,
                     )),
                     false,
                 )
@@ -6,9 +6,18 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+
 use crate::cleanup_queue::Cleanup;
 use crate::device::Device;
-use crate::error::Result;
+use crate::enums::*;
+use crate::error::{Error, Result};
+#[cfg(unix)]
+use crate::error::{ErrorAndSelf, ResultAndSelf};
+use crate::ffi::Array;
+#[cfg(unix)]
+use crate::ffi::{c_void, NonNull};
 use crate::types::*;
 
 /// A
@@ -18,6 +27,7 @@ use crate::types::*;
 pub struct Fence {
     handle: Option<Handle<VkFence>>,
     device: Arc<Device>,
+    export_handle_types: ExternalFenceHandleTypeFlags,
 }
 
 /// A
@@ -29,21 +39,45 @@ pub struct PendingFence {
     handle: Handle<VkFence>,
     device: Arc<Device>,
     resources: Cleanup,
+    export_handle_types: ExternalFenceHandleTypeFlags,
 }
 
 impl Fence {
     #[doc = crate::man_link!(vkCreateFence)]
     pub fn new(device: &Arc<Device>) -> Result<Self> {
+        Self::create(device, Default::default())
+    }
+
+    /// Create a fence which can be exported as any of `handle_types`. Requires
+    /// Vulkan 1.1 or VK_KHR_external_fence.
+    #[doc = crate::man_link!(VkExportFenceCreateInfo)]
+    pub fn new_exportable(
+        device: &Arc<Device>, handle_types: ExternalFenceHandleTypeFlags,
+    ) -> Result<Self> {
+        Self::create(device, handle_types)
+    }
+
+    fn create(
+        device: &Arc<Device>, export_handle_types: ExternalFenceHandleTypeFlags,
+    ) -> Result<Self> {
+        let export_info = ExportFenceCreateInfo {
+            handle_types: export_handle_types,
+            ..Default::default()
+        };
         let mut handle = None;
         unsafe {
             (device.fun.create_fence)(
                 device.handle(),
-                &Default::default(),
+                &FenceCreateInfo {
+                    next: (!export_handle_types.is_empty())
+                        .then_some(&export_info),
+                    ..Default::default()
+                },
                 None,
                 &mut handle,
             )?;
         }
-        Ok(Self { handle, device: device.clone() })
+        Ok(Self { handle, device: device.clone(), export_handle_types })
     }
 }
 
@@ -71,7 +105,84 @@ impl Fence {
             handle: self.handle.take().unwrap(),
             device: self.device.clone(),
             resources,
+            export_handle_types: self.export_handle_types,
+        }
+    }
+
+    /// Export the fence's payload as a file descriptor of type `handle_type`,
+    /// which must be one of the types the fence was
+    /// [created](Self::new_exportable()) with. Requires
+    /// VK_KHR_external_fence_fd. Returns [`Error::ExtensionNotPresent`] if the
+    /// extension is not enabled, and [`Error::InvalidArgument`] if
+    /// `handle_type` is not a single exportable type, or if it is `SYNC_FD`,
+    /// since the fence has no pending signal.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(vkGetFenceFdKHR)]
+    pub fn export_fd(
+        &self, handle_type: ExternalFenceHandleTypeFlags,
+    ) -> Result<OwnedFd> {
+        let handle = self.handle.as_ref().unwrap().borrow();
+        export_fd(&self.device, handle, self.export_handle_types, handle_type)
+    }
+
+    /// Import a payload from a file descriptor of type `handle_type`, which
+    /// must be `SYNC_FD`, with [`FenceImportFlags::TEMPORARY`]. The file
+    /// descriptor is owned by the implementation if the import succeeds, and
+    /// closed if it fails. Requires VK_KHR_external_fence_fd, and returns
+    /// [`Error::ExtensionNotPresent`] if it is not enabled.
+    ///
+    /// The fence is then treated as having a pending signal, so it can be
+    /// waited on. Waiting resets the fence, which removes the temporary
+    /// payload. Returns [`Error::InvalidArgument`] for other handle types or
+    /// flags. Permanently importing a payload shared with another fence is
+    /// not supported, since waiting on either fence would reset the other.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(vkImportFenceFdKHR)]
+    pub fn import_fd(
+        mut self, handle_type: ExternalFenceHandleTypeFlags, fd: OwnedFd,
+        flags: FenceImportFlags,
+    ) -> ResultAndSelf<PendingFence, Self> {
+        if handle_type != ExternalFenceHandleTypeFlags::SYNC_FD
+            || !flags.contains(FenceImportFlags::TEMPORARY)
+        {
+            return Err(ErrorAndSelf(Error::InvalidArgument, self));
+        }
+        let import_fence_fd = match self
+            .device
+            .try_get_proc_addr("vkImportFenceFdKHR\0")
+        {
+            Some(f) => unsafe {
+                std::mem::transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkDevice>,
+                        &ImportFenceFdInfoKHR,
+                    ) -> VkResult,
+                >(f)
+            },
+            None => return Err(ErrorAndSelf(Error::ExtensionNotPresent, self)),
+        };
+        let result = unsafe {
+            (import_fence_fd)(
+                self.device.handle(),
+                &ImportFenceFdInfoKHR {
+                    stype: Default::default(),
+                    next: Default::default(),
+                    fence: self.handle.as_mut().unwrap().borrow_mut(),
+                    flags,
+                    handle_type,
+                    fd: fd.as_raw_fd(),
+                },
+            )
+        };
+        if let Err(err) = result {
+            return Err(ErrorAndSelf(err.into(), self));
         }
+        // The implementation owns it now.
+        let _ = fd.into_raw_fd();
+        Ok(self.into_pending(Cleanup::empty()))
     }
 }
 
@@ -80,6 +191,31 @@ impl PendingFence {
     pub fn handle(&self) -> Ref<VkFence> {
         self.handle.borrow()
     }
+    /// Returns the associated device.
+    pub fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+    /// Export the fence's payload as a file descriptor of type `handle_type`,
+    /// which must be one of the types the fence was
+    /// [created](Fence::new_exportable()) with. Requires
+    /// VK_KHR_external_fence_fd. Returns [`Error::ExtensionNotPresent`] if the
+    /// extension is not enabled, and [`Error::InvalidArgument`] if
+    /// `handle_type` is not a single exportable type, or if it is `SYNC_FD`,
+    /// since exporting one resets the fence, after which it could not be
+    /// waited for.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(vkGetFenceFdKHR)]
+    pub fn export_fd(
+        &self, handle_type: ExternalFenceHandleTypeFlags,
+    ) -> Result<OwnedFd> {
+        export_fd(
+            &self.device,
+            self.handle(),
+            self.export_handle_types,
+            handle_type,
+        )
+    }
     /// Waits for the fence, decrements the reference count of any objects
     /// (including [`CommandPools`](crate::vk::CommandPool)) submitted to
     /// the queue, and resets the fence.
@@ -103,6 +239,196 @@ impl PendingFence {
                 (&[self.handle.borrow_mut()]).into(),
             )?;
         }
-        Ok(Fence { handle: Some(self.handle), device: self.device })
+        Ok(Fence {
+            handle: Some(self.handle),
+            device: self.device,
+            export_handle_types: self.export_handle_types,
+        })
+    }
+}
+
+impl PendingFence {
+    /// Waits for the fence for up to `timeout` nanoseconds. If it is signaled,
+    /// decrements the reference count of any objects submitted to the queue
+    /// and resets the fence, as in [`wait`](Self::wait()). Otherwise, or if an
+    /// error occurs, returns the fence unchanged; use
+    /// [`is_signaled`](Self::is_signaled()) to find out about errors such as
+    /// [`Error::DeviceLost`].
+    #[doc = crate::man_link!(vkWaitForFences)]
+    pub fn try_wait(
+        mut self, timeout: u64,
+    ) -> std::result::Result<Fence, PendingFence> {
+        let result = unsafe {
+            (self.device.fun.wait_for_fences)(
+                self.device.handle(),
+                1,
+                (&[self.handle.borrow()]).into(),
+                true.into(),
+                timeout,
+            )
+        };
+        if result.is_err() {
+            return Err(self);
+        }
+        self.resources.cleanup();
+        let result = unsafe {
+            (self.device.fun.reset_fences)(
+                self.device.handle(),
+                1,
+                // Safe because the the outer structure is owned here
+                (&[self.handle.borrow_mut()]).into(),
+            )
+        };
+        match result {
+            Ok(()) => Ok(Fence {
+                handle: Some(self.handle),
+                device: self.device,
+                export_handle_types: self.export_handle_types,
+            }),
+            Err(_) => Err(self),
+        }
+    }
+
+    /// Returns true if the fence is signaled, without blocking. If it is,
+    /// decrements the reference count of any objects submitted to the queue.
+    /// The fence stays pending; [`wait`](Self::wait()) will return immediately.
+    #[doc = crate::man_link!(vkGetFenceStatus)]
+    pub fn is_signaled(&self) -> Result<bool> {
+        let result = unsafe {
+            (self.device.fun.get_fence_status)(
+                self.device.handle(),
+                self.handle(),
+            )
+        };
+        match result.map_err(Error::from) {
+            Ok(()) => {
+                self.resources.cleanup();
+                Ok(true)
+            }
+            Err(Error::NotReady) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Waits for all of `fences` to be signaled, for up to `timeout`
+    /// nanoseconds, and decrements the reference count of any objects
+    /// submitted to the queues. The fences stay pending;
+    /// [`wait`](Self::wait()) will return immediately. Returns
+    /// [`Error::Timeout`] if the timeout expires first. Returns
+    /// [`Error::InvalidArgument`] if `fences` is empty or the fences belong to
+    /// different devices.
+    #[doc = crate::man_link!(vkWaitForFences)]
+    pub fn wait_all(fences: &[PendingFence], timeout: u64) -> Result<()> {
+        Self::wait_many(fences, true, timeout)?;
+        for fence in fences {
+            fence.resources.cleanup();
+        }
+        Ok(())
+    }
+
+    /// Waits for any of `fences` to be signaled, for up to `timeout`
+    /// nanoseconds, and returns the index of a signaled fence. The reference
+    /// counts of objects submitted with every signaled fence are decremented.
+    /// The fences stay pending; [`wait`](Self::wait()) will return
+    /// immediately for signaled fences. Returns [`Error::Timeout`] if the
+    /// timeout expires first. Returns [`Error::InvalidArgument`] if `fences` is
+    /// empty or the fences belong to different devices. Returns
+    /// [`Error::InvalidState`] if the wait succeeds but the implementation
+    /// then reports that none of the fences is signaled.
+    #[doc = crate::man_link!(vkWaitForFences)]
+    pub fn wait_any(fences: &[PendingFence], timeout: u64) -> Result<usize> {
+        Self::wait_many(fences, false, timeout)?;
+        let mut signaled = None;
+        for (i, fence) in fences.iter().enumerate() {
+            if fence.is_signaled()? && signaled.is_none() {
+                signaled = Some(i);
+            }
+        }
+        signaled.ok_or(Error::InvalidState)
+    }
+
+    pub(crate) fn wait_many(
+        fences: &[PendingFence], wait_all: bool, timeout: u64,
+    ) -> Result<()> {
+        let device = &fences.first().ok_or(Error::InvalidArgument)?.device;
+        if fences.iter().any(|f| !Arc::ptr_eq(&f.device, device)) {
+            return Err(Error::InvalidArgument);
+        }
+        let handles: Vec<_> = fences.iter().map(|f| f.handle()).collect();
+        unsafe {
+            (device.fun.wait_for_fences)(
+                device.handle(),
+                handles.len() as u32,
+                Array::from_slice(&handles).unwrap(),
+                wait_all.into(),
+                timeout,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Export a fence with reference transference.
+#[cfg(unix)]
+fn export_fd(
+    device: &Device, fence: Ref<VkFence>,
+    export_handle_types: ExternalFenceHandleTypeFlags,
+    handle_type: ExternalFenceHandleTypeFlags,
+) -> Result<OwnedFd> {
+    if handle_type.bits().count_ones() != 1
+        || !export_handle_types.contains(handle_type)
+        || handle_type == ExternalFenceHandleTypeFlags::SYNC_FD
+    {
+        return Err(Error::InvalidArgument);
+    }
+    let get_fence_fd: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        &FenceGetFdInfoKHR,
+        &mut RawFd,
+    ) -> VkResult = unsafe {
+        std::mem::transmute(
+            device
+                .try_get_proc_addr("vkGetFenceFdKHR\0")
+                .ok_or(Error::ExtensionNotPresent)?,
+        )
+    };
+    let mut fd = -1;
+    unsafe {
+        (get_fence_fd)(
+            device.handle(),
+            &FenceGetFdInfoKHR {
+                stype: Default::default(),
+                next: Default::default(),
+                fence,
+                handle_type,
+            },
+            &mut fd,
+        )?;
+        Ok(OwnedFd::from_raw_fd(fd))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn poll() -> vk::Result<()> {
+        let (dev, mut q) = crate::test_device()?;
+        let submit = |q: &mut vk::Queue| {
+            q.submit_with_fence(&mut [], vk::Fence::new(&dev)?)
+        };
+        let fences = vec![submit(&mut q)?, submit(&mut q)?];
+        vk::PendingFence::wait_all(&fences, u64::MAX)?;
+        assert!(vk::PendingFence::wait_any(&fences, u64::MAX)? < 2);
+        for fence in fences {
+            assert!(fence.is_signaled()?);
+            assert!(fence.try_wait(u64::MAX).is_ok());
+        }
+        assert_eq!(
+            vk::PendingFence::wait_all(&[], 0),
+            Err(vk::Error::InvalidArgument)
+        );
+        Ok(())
     }
 }
@@ -0,0 +1,219 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::{Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::error::Result;
+use crate::fence::{Fence, PendingFence};
+use crate::types::*;
+
+/// How long a waiter thread blocks before checking its fences again, in
+/// nanoseconds. Newly awaited fences do not wait for this.
+const WAIT_TIMEOUT: u64 = 1_000_000_000;
+
+/// A future which resolves when a fence is signaled. Created by awaiting a
+/// [`PendingFence`], or with
+/// [`IntoFuture::into_future`](std::future::IntoFuture::into_future()).
+///
+/// Each device has waiter threads, started when fences are awaited, which wait
+/// on the device's outstanding fences. An idle waiter thread is woken when a
+/// fence is awaited, and if every thread is already blocked on other fences,
+/// another one is started. Threads exit when another one is idle. When a
+/// fence is signaled the reference count of any objects submitted with it is
+/// decremented, as in [`PendingFence::wait`], even if the future has been
+/// dropped.
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub struct FenceFuture {
+    slot: Arc<Mutex<Slot>>,
+}
+
+#[derive(Debug, Default)]
+struct Slot {
+    result: Option<Result<Fence>>,
+    waker: Option<Waker>,
+}
+
+/// The state of a device's waiter threads.
+#[derive(Debug, Default)]
+pub(crate) struct FenceWaiter {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug, Default)]
+struct Shared {
+    state: Mutex<State>,
+    cond: Condvar,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Fences which no thread is waiting on.
+    fences: Vec<PendingFence>,
+    slots: Vec<Arc<Mutex<Slot>>>,
+    /// The number of threads waiting on `cond`.
+    idle: usize,
+    shutdown: bool,
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+impl IntoFuture for PendingFence {
+    type Output = Result<Fence>;
+    type IntoFuture = FenceFuture;
+    /// Hands the fence to the device's waiter thread.
+    fn into_future(self) -> FenceFuture {
+        let slot = Arc::new(Mutex::new(Slot::default()));
+        let device = self.device().clone();
+        device.waiter.push(self, slot.clone());
+        FenceFuture { slot }
+    }
+}
+
+impl Future for FenceFuture {
+    type Output = Result<Fence>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<Fence>> {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                match &slot.waker {
+                    Some(waker) if waker.will_wake(cx.waker()) => (),
+                    _ => slot.waker = Some(cx.waker().clone()),
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl FenceWaiter {
+    fn push(&self, fence: PendingFence, slot: Arc<Mutex<Slot>>) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.fences.push(fence);
+        state.slots.push(slot);
+        if state.idle > 0 {
+            self.shared.cond.notify_one();
+        } else {
+            let shared = self.shared.clone();
+            std::thread::Builder::new()
+                .name("maia fence waiter".into())
+                .spawn(move || shared.run())
+                .expect("Could not start fence waiter thread");
+        }
+    }
+}
+
+impl Drop for FenceWaiter {
+    /// Stops the waiter threads. Since pending fences hold a reference to the
+    /// device, there are none left at this point.
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().shutdown = true;
+        self.shared.cond.notify_all();
+    }
+}
+
+impl Shared {
+    fn run(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.fences.is_empty() {
+                // One idle thread is enough.
+                if state.shutdown || state.idle > 0 {
+                    return;
+                }
+                state.idle += 1;
+                while state.fences.is_empty() && !state.shutdown {
+                    state = self.cond.wait(state).unwrap();
+                }
+                state.idle -= 1;
+                continue;
+            }
+            let mut fences = std::mem::take(&mut state.fences);
+            let mut slots = std::mem::take(&mut state.slots);
+            drop(state);
+
+            // Errors show up in is_signaled
+            let _ = PendingFence::wait_many(&fences, false, WAIT_TIMEOUT);
+
+            let mut i = 0;
+            while i < fences.len() {
+                let result = match fences[i].is_signaled() {
+                    Ok(false) => {
+                        i += 1;
+                        continue;
+                    }
+                    // Returns immediately
+                    Ok(true) => fences.swap_remove(i).wait(),
+                    Err(err) => {
+                        // Leaks the fence, since its state is unknown
+                        drop(fences.swap_remove(i));
+                        Err(err)
+                    }
+                };
+                complete(&slots.swap_remove(i), result);
+            }
+
+            state = self.state.lock().unwrap();
+            state.fences.append(&mut fences);
+            state.slots.append(&mut slots);
+        }
+    }
+}
+
+fn complete(slot: &Mutex<Slot>, result: Result<Fence>) {
+    let mut slot = slot.lock().unwrap();
+    slot.result = Some(result);
+    let waker = slot.waker.take();
+    drop(slot);
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::future::{Future, IntoFuture};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+
+    use crate::vk;
+
+    struct ThreadWaker(std::thread::Thread);
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        let mut fut = Box::pin(fut);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(result) => return result,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn await_fence() -> vk::Result<()> {
+        let (dev, mut q) = crate::test_device()?;
+        let pending = q.submit_with_fence(&mut [], vk::Fence::new(&dev)?)?;
+        let fence = block_on(pending.into_future())?;
+        let pending = q.submit_with_fence(&mut [], fence)?;
+        drop(pending.into_future());
+        let pending = q.submit_with_fence(&mut [], vk::Fence::new(&dev)?)?;
+        block_on(pending.into_future())?;
+        Ok(())
+    }
+}
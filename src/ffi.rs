@@ -26,6 +26,58 @@ impl Debug for Null {
     }
 }
 
+/// Structures which can be chained onto a `T` with an [`ExtensionChain`].
+///
+/// # Safety
+/// `Self` must be a `#[repr(C)]` Vulkan structure which is valid in the `pNext`
+/// chain of `T`, and whose own `pNext` chain is also valid for `T`. Maia reads
+/// the `sType` of each structure in the chain, and a structure with the `sType`
+/// of a structure defined by Maia must be that structure.
+pub unsafe trait Extends<T> {}
+
+/// A borrowed `pNext` chain of extension structures for a `T`. Represented as
+/// a nullable pointer to the first structure.
+#[repr(transparent)]
+pub struct ExtensionChain<'a, T> {
+    head: Option<NonNull<c_void>>,
+    _lt: PhantomData<&'a c_void>,
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> ExtensionChain<'a, T> {
+    /// A chain starting with `head`.
+    pub fn new<S: Extends<T>>(head: &'a S) -> Self {
+        Self {
+            head: Some(NonNull::from(head).cast()),
+            _lt: PhantomData,
+            _ty: PhantomData,
+        }
+    }
+    /// The first structure of the chain.
+    pub(crate) fn head(self) -> Option<NonNull<c_void>> {
+        self.head
+    }
+}
+
+impl<'a, T> Default for ExtensionChain<'a, T> {
+    fn default() -> Self {
+        Self { head: None, _lt: PhantomData, _ty: PhantomData }
+    }
+}
+
+impl<'a, T> Copy for ExtensionChain<'a, T> {}
+impl<'a, T> Clone for ExtensionChain<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Debug for ExtensionChain<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.head.fmt(f)
+    }
+}
+
 /// An immutably borrowed, null-terminated utf-8 string, represented as
 /// a non-null c `const char*`.
 #[repr(transparent)]
@@ -225,6 +277,10 @@ impl<'a, T> Slice<'a, T> {
     }
     /// Convert back into a normal rust slice
     pub fn as_slice(&self) -> &'a [T] {
+        // Slices filled in by the implementation may be null when empty.
+        if self.count == 0 {
+            return &[];
+        }
         unsafe {
             let len = self.count as usize;
             std::slice::from_raw_parts(self.ptr, len)
@@ -0,0 +1,1072 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::enums::{Format, ImageAspectFlags};
+use crate::types::{Extent2D, Extent3D};
+
+/// The numeric format of the components of a [`Format`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum NumericType {
+    Unorm,
+    Snorm,
+    Uscaled,
+    Sscaled,
+    Uint,
+    Sint,
+    Ufloat,
+    Sfloat,
+    Srgb,
+}
+
+/// The number of bits in each component of a [`Format`]. Components which the
+/// format does not have are zero, as are all components of compressed
+/// formats.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct ComponentBits {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+    pub depth: u8,
+    pub stencil: u8,
+}
+
+/// A
+#[doc = crate::spec_link!("format compatibility class", "43", "formats-compatibility-classes")]
+/// Image views of an image with a different format must have a format of the
+/// same class.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct CompatibilityClass(&'static str);
+
+impl CompatibilityClass {
+    /// The name of the class in the specification, for example "32-bit" or
+    /// "BC1_RGB".
+    pub fn name(self) -> &'static str {
+        self.0
+    }
+}
+
+struct FormatInfo {
+    block_size: u8,
+    block_extent: (u8, u8),
+    aspects: ImageAspectFlags,
+    numeric_type: NumericType,
+    bits: [u8; 6],
+    class: &'static str,
+}
+
+fn info(
+    block_size: u8, width: u8, height: u8, aspects: ImageAspectFlags,
+    numeric_type: NumericType, bits: [u8; 6], class: &'static str,
+) -> Option<FormatInfo> {
+    Some(FormatInfo {
+        block_size,
+        block_extent: (width, height),
+        aspects,
+        numeric_type,
+        bits,
+        class,
+    })
+}
+
+impl Format {
+    fn info(self) -> Option<FormatInfo> {
+        use NumericType::*;
+        const COLOR: ImageAspectFlags = ImageAspectFlags::COLOR;
+        const DEPTH: ImageAspectFlags = ImageAspectFlags::DEPTH;
+        const STENCIL: ImageAspectFlags = ImageAspectFlags::STENCIL;
+        const PLANE_0: ImageAspectFlags = ImageAspectFlags::PLANE_0;
+        const PLANE_1: ImageAspectFlags = ImageAspectFlags::PLANE_1;
+        const PLANE_2: ImageAspectFlags = ImageAspectFlags::PLANE_2;
+        match self {
+            Self::R4G4_UNORM_PACK8 => {
+                info(1, 1, 1, COLOR, Unorm, [4, 4, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R4G4B4A4_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [4, 4, 4, 4, 0, 0], "16-bit")
+            }
+            Self::B4G4R4A4_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [4, 4, 4, 4, 0, 0], "16-bit")
+            }
+            Self::R5G6B5_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [5, 6, 5, 0, 0, 0], "16-bit")
+            }
+            Self::B5G6R5_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [5, 6, 5, 0, 0, 0], "16-bit")
+            }
+            Self::R5G5B5A1_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [5, 5, 5, 1, 0, 0], "16-bit")
+            }
+            Self::B5G5R5A1_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [5, 5, 5, 1, 0, 0], "16-bit")
+            }
+            Self::A1R5G5B5_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [5, 5, 5, 1, 0, 0], "16-bit")
+            }
+            Self::R8_UNORM => {
+                info(1, 1, 1, COLOR, Unorm, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8_SNORM => {
+                info(1, 1, 1, COLOR, Snorm, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8_USCALED => {
+                info(1, 1, 1, COLOR, Uscaled, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8_SSCALED => {
+                info(1, 1, 1, COLOR, Sscaled, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8_UINT => {
+                info(1, 1, 1, COLOR, Uint, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8_SINT => {
+                info(1, 1, 1, COLOR, Sint, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8_SRGB => {
+                info(1, 1, 1, COLOR, Srgb, [8, 0, 0, 0, 0, 0], "8-bit")
+            }
+            Self::R8G8_UNORM => {
+                info(2, 1, 1, COLOR, Unorm, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8_SNORM => {
+                info(2, 1, 1, COLOR, Snorm, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8_USCALED => {
+                info(2, 1, 1, COLOR, Uscaled, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8_SSCALED => {
+                info(2, 1, 1, COLOR, Sscaled, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8_UINT => {
+                info(2, 1, 1, COLOR, Uint, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8_SINT => {
+                info(2, 1, 1, COLOR, Sint, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8_SRGB => {
+                info(2, 1, 1, COLOR, Srgb, [8, 8, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R8G8B8_UNORM => {
+                info(3, 1, 1, COLOR, Unorm, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8_SNORM => {
+                info(3, 1, 1, COLOR, Snorm, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8_USCALED => {
+                info(3, 1, 1, COLOR, Uscaled, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8_SSCALED => {
+                info(3, 1, 1, COLOR, Sscaled, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8_UINT => {
+                info(3, 1, 1, COLOR, Uint, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8_SINT => {
+                info(3, 1, 1, COLOR, Sint, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8_SRGB => {
+                info(3, 1, 1, COLOR, Srgb, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_UNORM => {
+                info(3, 1, 1, COLOR, Unorm, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_SNORM => {
+                info(3, 1, 1, COLOR, Snorm, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_USCALED => {
+                info(3, 1, 1, COLOR, Uscaled, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_SSCALED => {
+                info(3, 1, 1, COLOR, Sscaled, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_UINT => {
+                info(3, 1, 1, COLOR, Uint, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_SINT => {
+                info(3, 1, 1, COLOR, Sint, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::B8G8R8_SRGB => {
+                info(3, 1, 1, COLOR, Srgb, [8, 8, 8, 0, 0, 0], "24-bit")
+            }
+            Self::R8G8B8A8_UNORM => {
+                info(4, 1, 1, COLOR, Unorm, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::R8G8B8A8_SNORM => {
+                info(4, 1, 1, COLOR, Snorm, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::R8G8B8A8_USCALED => {
+                info(4, 1, 1, COLOR, Uscaled, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::R8G8B8A8_SSCALED => {
+                info(4, 1, 1, COLOR, Sscaled, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::R8G8B8A8_UINT => {
+                info(4, 1, 1, COLOR, Uint, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::R8G8B8A8_SINT => {
+                info(4, 1, 1, COLOR, Sint, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::R8G8B8A8_SRGB => {
+                info(4, 1, 1, COLOR, Srgb, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_UNORM => {
+                info(4, 1, 1, COLOR, Unorm, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_SNORM => {
+                info(4, 1, 1, COLOR, Snorm, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_USCALED => {
+                info(4, 1, 1, COLOR, Uscaled, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_SSCALED => {
+                info(4, 1, 1, COLOR, Sscaled, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_UINT => {
+                info(4, 1, 1, COLOR, Uint, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_SINT => {
+                info(4, 1, 1, COLOR, Sint, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::B8G8R8A8_SRGB => {
+                info(4, 1, 1, COLOR, Srgb, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_UNORM_PACK32 => {
+                info(4, 1, 1, COLOR, Unorm, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_SNORM_PACK32 => {
+                info(4, 1, 1, COLOR, Snorm, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_USCALED_PACK32 => {
+                info(4, 1, 1, COLOR, Uscaled, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_SSCALED_PACK32 => {
+                info(4, 1, 1, COLOR, Sscaled, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_UINT_PACK32 => {
+                info(4, 1, 1, COLOR, Uint, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_SINT_PACK32 => {
+                info(4, 1, 1, COLOR, Sint, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A8B8G8R8_SRGB_PACK32 => {
+                info(4, 1, 1, COLOR, Srgb, [8, 8, 8, 8, 0, 0], "32-bit")
+            }
+            Self::A2R10G10B10_UNORM_PACK32 => {
+                info(4, 1, 1, COLOR, Unorm, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2R10G10B10_SNORM_PACK32 => {
+                info(4, 1, 1, COLOR, Snorm, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2R10G10B10_USCALED_PACK32 => {
+                info(4, 1, 1, COLOR, Uscaled, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2R10G10B10_SSCALED_PACK32 => {
+                info(4, 1, 1, COLOR, Sscaled, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2R10G10B10_UINT_PACK32 => {
+                info(4, 1, 1, COLOR, Uint, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2R10G10B10_SINT_PACK32 => {
+                info(4, 1, 1, COLOR, Sint, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2B10G10R10_UNORM_PACK32 => {
+                info(4, 1, 1, COLOR, Unorm, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2B10G10R10_SNORM_PACK32 => {
+                info(4, 1, 1, COLOR, Snorm, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2B10G10R10_USCALED_PACK32 => {
+                info(4, 1, 1, COLOR, Uscaled, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2B10G10R10_SSCALED_PACK32 => {
+                info(4, 1, 1, COLOR, Sscaled, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2B10G10R10_UINT_PACK32 => {
+                info(4, 1, 1, COLOR, Uint, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::A2B10G10R10_SINT_PACK32 => {
+                info(4, 1, 1, COLOR, Sint, [10, 10, 10, 2, 0, 0], "32-bit")
+            }
+            Self::R16_UNORM => {
+                info(2, 1, 1, COLOR, Unorm, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16_SNORM => {
+                info(2, 1, 1, COLOR, Snorm, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16_USCALED => {
+                info(2, 1, 1, COLOR, Uscaled, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16_SSCALED => {
+                info(2, 1, 1, COLOR, Sscaled, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16_UINT => {
+                info(2, 1, 1, COLOR, Uint, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16_SINT => {
+                info(2, 1, 1, COLOR, Sint, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16_SFLOAT => {
+                info(2, 1, 1, COLOR, Sfloat, [16, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R16G16_UNORM => {
+                info(4, 1, 1, COLOR, Unorm, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16_SNORM => {
+                info(4, 1, 1, COLOR, Snorm, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16_USCALED => {
+                info(4, 1, 1, COLOR, Uscaled, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16_SSCALED => {
+                info(4, 1, 1, COLOR, Sscaled, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16_UINT => {
+                info(4, 1, 1, COLOR, Uint, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16_SINT => {
+                info(4, 1, 1, COLOR, Sint, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16_SFLOAT => {
+                info(4, 1, 1, COLOR, Sfloat, [16, 16, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R16G16B16_UNORM => {
+                info(6, 1, 1, COLOR, Unorm, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16_SNORM => {
+                info(6, 1, 1, COLOR, Snorm, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16_USCALED => {
+                info(6, 1, 1, COLOR, Uscaled, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16_SSCALED => {
+                info(6, 1, 1, COLOR, Sscaled, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16_UINT => {
+                info(6, 1, 1, COLOR, Uint, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16_SINT => {
+                info(6, 1, 1, COLOR, Sint, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16_SFLOAT => {
+                info(6, 1, 1, COLOR, Sfloat, [16, 16, 16, 0, 0, 0], "48-bit")
+            }
+            Self::R16G16B16A16_UNORM => {
+                info(8, 1, 1, COLOR, Unorm, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R16G16B16A16_SNORM => {
+                info(8, 1, 1, COLOR, Snorm, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R16G16B16A16_USCALED => {
+                info(8, 1, 1, COLOR, Uscaled, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R16G16B16A16_SSCALED => {
+                info(8, 1, 1, COLOR, Sscaled, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R16G16B16A16_UINT => {
+                info(8, 1, 1, COLOR, Uint, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R16G16B16A16_SINT => {
+                info(8, 1, 1, COLOR, Sint, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R16G16B16A16_SFLOAT => {
+                info(8, 1, 1, COLOR, Sfloat, [16, 16, 16, 16, 0, 0], "64-bit")
+            }
+            Self::R32_UINT => {
+                info(4, 1, 1, COLOR, Uint, [32, 0, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R32_SINT => {
+                info(4, 1, 1, COLOR, Sint, [32, 0, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R32_SFLOAT => {
+                info(4, 1, 1, COLOR, Sfloat, [32, 0, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R32G32_UINT => {
+                info(8, 1, 1, COLOR, Uint, [32, 32, 0, 0, 0, 0], "64-bit")
+            }
+            Self::R32G32_SINT => {
+                info(8, 1, 1, COLOR, Sint, [32, 32, 0, 0, 0, 0], "64-bit")
+            }
+            Self::R32G32_SFLOAT => {
+                info(8, 1, 1, COLOR, Sfloat, [32, 32, 0, 0, 0, 0], "64-bit")
+            }
+            Self::R32G32B32_UINT => {
+                info(12, 1, 1, COLOR, Uint, [32, 32, 32, 0, 0, 0], "96-bit")
+            }
+            Self::R32G32B32_SINT => {
+                info(12, 1, 1, COLOR, Sint, [32, 32, 32, 0, 0, 0], "96-bit")
+            }
+            Self::R32G32B32_SFLOAT => {
+                info(12, 1, 1, COLOR, Sfloat, [32, 32, 32, 0, 0, 0], "96-bit")
+            }
+            Self::R32G32B32A32_UINT => {
+                info(16, 1, 1, COLOR, Uint, [32, 32, 32, 32, 0, 0], "128-bit")
+            }
+            Self::R32G32B32A32_SINT => {
+                info(16, 1, 1, COLOR, Sint, [32, 32, 32, 32, 0, 0], "128-bit")
+            }
+            Self::R32G32B32A32_SFLOAT => {
+                info(16, 1, 1, COLOR, Sfloat, [32, 32, 32, 32, 0, 0], "128-bit")
+            }
+            Self::R64_UINT => {
+                info(8, 1, 1, COLOR, Uint, [64, 0, 0, 0, 0, 0], "64-bit")
+            }
+            Self::R64_SINT => {
+                info(8, 1, 1, COLOR, Sint, [64, 0, 0, 0, 0, 0], "64-bit")
+            }
+            Self::R64_SFLOAT => {
+                info(8, 1, 1, COLOR, Sfloat, [64, 0, 0, 0, 0, 0], "64-bit")
+            }
+            Self::R64G64_UINT => {
+                info(16, 1, 1, COLOR, Uint, [64, 64, 0, 0, 0, 0], "128-bit")
+            }
+            Self::R64G64_SINT => {
+                info(16, 1, 1, COLOR, Sint, [64, 64, 0, 0, 0, 0], "128-bit")
+            }
+            Self::R64G64_SFLOAT => {
+                info(16, 1, 1, COLOR, Sfloat, [64, 64, 0, 0, 0, 0], "128-bit")
+            }
+            Self::R64G64B64_UINT => {
+                info(24, 1, 1, COLOR, Uint, [64, 64, 64, 0, 0, 0], "192-bit")
+            }
+            Self::R64G64B64_SINT => {
+                info(24, 1, 1, COLOR, Sint, [64, 64, 64, 0, 0, 0], "192-bit")
+            }
+            Self::R64G64B64_SFLOAT => {
+                info(24, 1, 1, COLOR, Sfloat, [64, 64, 64, 0, 0, 0], "192-bit")
+            }
+            Self::R64G64B64A64_UINT => {
+                info(32, 1, 1, COLOR, Uint, [64, 64, 64, 64, 0, 0], "256-bit")
+            }
+            Self::R64G64B64A64_SINT => {
+                info(32, 1, 1, COLOR, Sint, [64, 64, 64, 64, 0, 0], "256-bit")
+            }
+            Self::R64G64B64A64_SFLOAT => {
+                info(32, 1, 1, COLOR, Sfloat, [64, 64, 64, 64, 0, 0], "256-bit")
+            }
+            Self::B10G11R11_UFLOAT_PACK32 => {
+                info(4, 1, 1, COLOR, Ufloat, [11, 11, 10, 0, 0, 0], "32-bit")
+            }
+            Self::E5B9G9R9_UFLOAT_PACK32 => {
+                info(4, 1, 1, COLOR, Ufloat, [9, 9, 9, 0, 0, 0], "32-bit")
+            }
+            Self::D16_UNORM => {
+                info(2, 1, 1, DEPTH, Unorm, [0, 0, 0, 0, 16, 0], "D16")
+            }
+            Self::X8_D24_UNORM_PACK32 => {
+                info(4, 1, 1, DEPTH, Unorm, [0, 0, 0, 0, 24, 0], "D24")
+            }
+            Self::D32_SFLOAT => {
+                info(4, 1, 1, DEPTH, Sfloat, [0, 0, 0, 0, 32, 0], "D32")
+            }
+            Self::S8_UINT => {
+                info(1, 1, 1, STENCIL, Uint, [0, 0, 0, 0, 0, 8], "S8")
+            }
+            Self::D16_UNORM_S8_UINT => info(
+                3,
+                1,
+                1,
+                DEPTH | STENCIL,
+                Unorm,
+                [0, 0, 0, 0, 16, 8],
+                "D16S8",
+            ),
+            Self::D24_UNORM_S8_UINT => info(
+                4,
+                1,
+                1,
+                DEPTH | STENCIL,
+                Unorm,
+                [0, 0, 0, 0, 24, 8],
+                "D24S8",
+            ),
+            Self::D32_SFLOAT_S8_UINT => info(
+                5,
+                1,
+                1,
+                DEPTH | STENCIL,
+                Sfloat,
+                [0, 0, 0, 0, 32, 8],
+                "D32S8",
+            ),
+            Self::BC1_RGB_UNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC1_RGB")
+            }
+            Self::BC1_RGB_SRGB_BLOCK => {
+                info(8, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "BC1_RGB")
+            }
+            Self::BC1_RGBA_UNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC1_RGBA")
+            }
+            Self::BC1_RGBA_SRGB_BLOCK => {
+                info(8, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "BC1_RGBA")
+            }
+            Self::BC2_UNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC2")
+            }
+            Self::BC2_SRGB_BLOCK => {
+                info(16, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "BC2")
+            }
+            Self::BC3_UNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC3")
+            }
+            Self::BC3_SRGB_BLOCK => {
+                info(16, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "BC3")
+            }
+            Self::BC4_UNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC4")
+            }
+            Self::BC4_SNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Snorm, [0, 0, 0, 0, 0, 0], "BC4")
+            }
+            Self::BC5_UNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC5")
+            }
+            Self::BC5_SNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Snorm, [0, 0, 0, 0, 0, 0], "BC5")
+            }
+            Self::BC6H_UFLOAT_BLOCK => {
+                info(16, 4, 4, COLOR, Ufloat, [0, 0, 0, 0, 0, 0], "BC6H")
+            }
+            Self::BC6H_SFLOAT_BLOCK => {
+                info(16, 4, 4, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "BC6H")
+            }
+            Self::BC7_UNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "BC7")
+            }
+            Self::BC7_SRGB_BLOCK => {
+                info(16, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "BC7")
+            }
+            Self::ETC2_R8G8B8_UNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ETC2_RGB")
+            }
+            Self::ETC2_R8G8B8_SRGB_BLOCK => {
+                info(8, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ETC2_RGB")
+            }
+            Self::ETC2_R8G8B8A1_UNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ETC2_RGBA")
+            }
+            Self::ETC2_R8G8B8A1_SRGB_BLOCK => {
+                info(8, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ETC2_RGBA")
+            }
+            Self::ETC2_R8G8B8A8_UNORM_BLOCK => info(
+                16,
+                4,
+                4,
+                COLOR,
+                Unorm,
+                [0, 0, 0, 0, 0, 0],
+                "ETC2_EAC_RGBA",
+            ),
+            Self::ETC2_R8G8B8A8_SRGB_BLOCK => {
+                info(16, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ETC2_EAC_RGBA")
+            }
+            Self::EAC_R11_UNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "EAC_R")
+            }
+            Self::EAC_R11_SNORM_BLOCK => {
+                info(8, 4, 4, COLOR, Snorm, [0, 0, 0, 0, 0, 0], "EAC_R")
+            }
+            Self::EAC_R11G11_UNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "EAC_RG")
+            }
+            Self::EAC_R11G11_SNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Snorm, [0, 0, 0, 0, 0, 0], "EAC_RG")
+            }
+            Self::ASTC_4X4_UNORM_BLOCK => {
+                info(16, 4, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_4x4")
+            }
+            Self::ASTC_4X4_SRGB_BLOCK => {
+                info(16, 4, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_4x4")
+            }
+            Self::ASTC_5X4_UNORM_BLOCK => {
+                info(16, 5, 4, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_5x4")
+            }
+            Self::ASTC_5X4_SRGB_BLOCK => {
+                info(16, 5, 4, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_5x4")
+            }
+            Self::ASTC_5X5_UNORM_BLOCK => {
+                info(16, 5, 5, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_5x5")
+            }
+            Self::ASTC_5X5_SRGB_BLOCK => {
+                info(16, 5, 5, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_5x5")
+            }
+            Self::ASTC_6X5_UNORM_BLOCK => {
+                info(16, 6, 5, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_6x5")
+            }
+            Self::ASTC_6X5_SRGB_BLOCK => {
+                info(16, 6, 5, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_6x5")
+            }
+            Self::ASTC_6X6_UNORM_BLOCK => {
+                info(16, 6, 6, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_6x6")
+            }
+            Self::ASTC_6X6_SRGB_BLOCK => {
+                info(16, 6, 6, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_6x6")
+            }
+            Self::ASTC_8X5_UNORM_BLOCK => {
+                info(16, 8, 5, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_8x5")
+            }
+            Self::ASTC_8X5_SRGB_BLOCK => {
+                info(16, 8, 5, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_8x5")
+            }
+            Self::ASTC_8X6_UNORM_BLOCK => {
+                info(16, 8, 6, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_8x6")
+            }
+            Self::ASTC_8X6_SRGB_BLOCK => {
+                info(16, 8, 6, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_8x6")
+            }
+            Self::ASTC_8X8_UNORM_BLOCK => {
+                info(16, 8, 8, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_8x8")
+            }
+            Self::ASTC_8X8_SRGB_BLOCK => {
+                info(16, 8, 8, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_8x8")
+            }
+            Self::ASTC_10X5_UNORM_BLOCK => {
+                info(16, 10, 5, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_10x5")
+            }
+            Self::ASTC_10X5_SRGB_BLOCK => {
+                info(16, 10, 5, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_10x5")
+            }
+            Self::ASTC_10X6_UNORM_BLOCK => {
+                info(16, 10, 6, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_10x6")
+            }
+            Self::ASTC_10X6_SRGB_BLOCK => {
+                info(16, 10, 6, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_10x6")
+            }
+            Self::ASTC_10X8_UNORM_BLOCK => {
+                info(16, 10, 8, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_10x8")
+            }
+            Self::ASTC_10X8_SRGB_BLOCK => {
+                info(16, 10, 8, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_10x8")
+            }
+            Self::ASTC_10X10_UNORM_BLOCK => {
+                info(16, 10, 10, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_10x10")
+            }
+            Self::ASTC_10X10_SRGB_BLOCK => {
+                info(16, 10, 10, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_10x10")
+            }
+            Self::ASTC_12X10_UNORM_BLOCK => {
+                info(16, 12, 10, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_12x10")
+            }
+            Self::ASTC_12X10_SRGB_BLOCK => {
+                info(16, 12, 10, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_12x10")
+            }
+            Self::ASTC_12X12_UNORM_BLOCK => {
+                info(16, 12, 12, COLOR, Unorm, [0, 0, 0, 0, 0, 0], "ASTC_12x12")
+            }
+            Self::ASTC_12X12_SRGB_BLOCK => {
+                info(16, 12, 12, COLOR, Srgb, [0, 0, 0, 0, 0, 0], "ASTC_12x12")
+            }
+            Self::G8B8G8R8_422_UNORM => info(
+                4,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "32-bit G8B8G8R8",
+            ),
+            Self::B8G8R8G8_422_UNORM => info(
+                4,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "32-bit B8G8R8G8",
+            ),
+            Self::G8_B8_R8_3PLANE_420_UNORM => info(
+                3,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1 | PLANE_2,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "8-bit 3-plane 420",
+            ),
+            Self::G8_B8R8_2PLANE_420_UNORM => info(
+                3,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "8-bit 2-plane 420",
+            ),
+            Self::G8_B8_R8_3PLANE_422_UNORM => info(
+                3,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1 | PLANE_2,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "8-bit 3-plane 422",
+            ),
+            Self::G8_B8R8_2PLANE_422_UNORM => info(
+                3,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "8-bit 2-plane 422",
+            ),
+            Self::G8_B8_R8_3PLANE_444_UNORM => info(
+                3,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1 | PLANE_2,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "8-bit 3-plane 444",
+            ),
+            Self::R10X6_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [10, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R10X6G10X6_UNORM_2PACK16 => {
+                info(4, 1, 1, COLOR, Unorm, [10, 10, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R10X6G10X6B10X6A10X6_UNORM_4PACK16 => info(
+                8,
+                1,
+                1,
+                COLOR,
+                Unorm,
+                [10, 10, 10, 10, 0, 0],
+                "64-bit R10G10B10A10",
+            ),
+            Self::G10X6B10X6G10X6R10X6_422_UNORM_4PACK16 => info(
+                8,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [10, 10, 10, 0, 0, 0],
+                "64-bit G10B10G10R10",
+            ),
+            Self::B10X6G10X6R10X6G10X6_422_UNORM_4PACK16 => info(
+                8,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [10, 10, 10, 0, 0, 0],
+                "64-bit B10G10R10G10",
+            ),
+            Self::R12X4_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [12, 0, 0, 0, 0, 0], "16-bit")
+            }
+            Self::R12X4G12X4_UNORM_2PACK16 => {
+                info(4, 1, 1, COLOR, Unorm, [12, 12, 0, 0, 0, 0], "32-bit")
+            }
+            Self::R12X4G12X4B12X4A12X4_UNORM_4PACK16 => info(
+                8,
+                1,
+                1,
+                COLOR,
+                Unorm,
+                [12, 12, 12, 12, 0, 0],
+                "64-bit R12G12B12A12",
+            ),
+            Self::G12X4B12X4G12X4R12X4_422_UNORM_4PACK16 => info(
+                8,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [12, 12, 12, 0, 0, 0],
+                "64-bit G12B12G12R12",
+            ),
+            Self::B12X4G12X4R12X4G12X4_422_UNORM_4PACK16 => info(
+                8,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [12, 12, 12, 0, 0, 0],
+                "64-bit B12G12R12G12",
+            ),
+            Self::G16B16G16R16_422_UNORM => info(
+                8,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "64-bit G16B16G16R16",
+            ),
+            Self::B16G16R16G16_422_UNORM => info(
+                8,
+                2,
+                1,
+                COLOR,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "64-bit B16G16R16G16",
+            ),
+            Self::G16_B16_R16_3PLANE_420_UNORM => info(
+                6,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1 | PLANE_2,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "16-bit 3-plane 420",
+            ),
+            Self::G16_B16R16_2PLANE_420_UNORM => info(
+                6,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "16-bit 2-plane 420",
+            ),
+            Self::G16_B16_R16_3PLANE_422_UNORM => info(
+                6,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1 | PLANE_2,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "16-bit 3-plane 422",
+            ),
+            Self::G16_B16R16_2PLANE_422_UNORM => info(
+                6,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "16-bit 2-plane 422",
+            ),
+            Self::G16_B16_R16_3PLANE_444_UNORM => info(
+                6,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1 | PLANE_2,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "16-bit 3-plane 444",
+            ),
+            Self::G8_B8R8_2PLANE_444_UNORM => info(
+                3,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1,
+                Unorm,
+                [8, 8, 8, 0, 0, 0],
+                "8-bit 2-plane 444",
+            ),
+            Self::G16_B16R16_2PLANE_444_UNORM => info(
+                6,
+                1,
+                1,
+                COLOR | PLANE_0 | PLANE_1,
+                Unorm,
+                [16, 16, 16, 0, 0, 0],
+                "16-bit 2-plane 444",
+            ),
+            Self::A4R4G4B4_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [4, 4, 4, 4, 0, 0], "16-bit")
+            }
+            Self::A4B4G4R4_UNORM_PACK16 => {
+                info(2, 1, 1, COLOR, Unorm, [4, 4, 4, 4, 0, 0], "16-bit")
+            }
+            Self::ASTC_4X4_SFLOAT_BLOCK => {
+                info(16, 4, 4, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_4x4")
+            }
+            Self::ASTC_5X4_SFLOAT_BLOCK => {
+                info(16, 5, 4, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_5x4")
+            }
+            Self::ASTC_5X5_SFLOAT_BLOCK => {
+                info(16, 5, 5, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_5x5")
+            }
+            Self::ASTC_6X5_SFLOAT_BLOCK => {
+                info(16, 6, 5, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_6x5")
+            }
+            Self::ASTC_6X6_SFLOAT_BLOCK => {
+                info(16, 6, 6, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_6x6")
+            }
+            Self::ASTC_8X5_SFLOAT_BLOCK => {
+                info(16, 8, 5, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_8x5")
+            }
+            Self::ASTC_8X6_SFLOAT_BLOCK => {
+                info(16, 8, 6, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_8x6")
+            }
+            Self::ASTC_8X8_SFLOAT_BLOCK => {
+                info(16, 8, 8, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_8x8")
+            }
+            Self::ASTC_10X5_SFLOAT_BLOCK => {
+                info(16, 10, 5, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_10x5")
+            }
+            Self::ASTC_10X6_SFLOAT_BLOCK => {
+                info(16, 10, 6, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_10x6")
+            }
+            Self::ASTC_10X8_SFLOAT_BLOCK => {
+                info(16, 10, 8, COLOR, Sfloat, [0, 0, 0, 0, 0, 0], "ASTC_10x8")
+            }
+            Self::ASTC_10X10_SFLOAT_BLOCK => info(
+                16,
+                10,
+                10,
+                COLOR,
+                Sfloat,
+                [0, 0, 0, 0, 0, 0],
+                "ASTC_10x10",
+            ),
+            Self::ASTC_12X10_SFLOAT_BLOCK => info(
+                16,
+                12,
+                10,
+                COLOR,
+                Sfloat,
+                [0, 0, 0, 0, 0, 0],
+                "ASTC_12x10",
+            ),
+            Self::ASTC_12X12_SFLOAT_BLOCK => info(
+                16,
+                12,
+                12,
+                COLOR,
+                Sfloat,
+                [0, 0, 0, 0, 0, 0],
+                "ASTC_12x12",
+            ),
+
+            _ => None,
+        }
+    }
+
+    /// Number of bytes per texel block. For multi-planar formats, this is the
+    /// sum of the sizes of one texel of each plane. Zero for
+    /// [`Format::UNDEFINED`].
+    pub fn block_size(self) -> u32 {
+        self.info().map_or(0, |i| i.block_size as u32)
+    }
+    /// Number of texels in each dimension of a texel block.
+    pub fn block_extent(self) -> Extent3D {
+        let (width, height) = self.info().map_or((1, 1), |i| i.block_extent);
+        Extent3D { width: width as u32, height: height as u32, depth: 1 }
+    }
+    /// The aspects of images of this format. Multi-planar formats have the
+    /// color aspect and one aspect for each plane.
+    pub fn aspects(self) -> ImageAspectFlags {
+        self.info().map_or(ImageAspectFlags::empty(), |i| i.aspects)
+    }
+    /// Returns true if the format has a depth component.
+    pub fn is_depth(self) -> bool {
+        self.aspects().contains(ImageAspectFlags::DEPTH)
+    }
+    /// Returns true if the format has a stencil component.
+    pub fn is_stencil(self) -> bool {
+        self.aspects().contains(ImageAspectFlags::STENCIL)
+    }
+    /// Returns true if the format is block-compressed.
+    pub fn is_compressed(self) -> bool {
+        self.info().map_or(false, |i| i.bits == [0; 6])
+    }
+    /// Returns true if the format has more than one plane.
+    pub fn is_multi_planar(self) -> bool {
+        self.aspects().contains(ImageAspectFlags::PLANE_0)
+    }
+    /// Returns true if the color components are sRGB-encoded.
+    pub fn is_srgb(self) -> bool {
+        self.numeric_type() == Some(NumericType::Srgb)
+    }
+    /// The numeric format of the components. For combined depth/stencil
+    /// formats, this is the type of the depth component. `None` for
+    /// [`Format::UNDEFINED`].
+    pub fn numeric_type(self) -> Option<NumericType> {
+        self.info().map(|i| i.numeric_type)
+    }
+    /// The number of bits in each component.
+    pub fn component_bits(self) -> ComponentBits {
+        let [red, green, blue, alpha, depth, stencil] =
+            self.info().map_or([0; 6], |i| i.bits);
+        ComponentBits { red, green, blue, alpha, depth, stencil }
+    }
+    /// The compatibility class of the format. `None` for
+    /// [`Format::UNDEFINED`].
+    pub fn compatibility_class(self) -> Option<CompatibilityClass> {
+        self.info().map(|i| CompatibilityClass(i.class))
+    }
+
+    /// Number of bytes per block
+    #[deprecated = "Use block_size()"]
+    pub fn bytes(self) -> u64 {
+        self.block_size() as u64
+    }
+    /// Number of texels per block
+    #[deprecated = "Use block_extent()"]
+    pub fn texels(self) -> Extent2D {
+        let extent = self.block_extent();
+        Extent2D { width: extent.width, height: extent.height }
+    }
+
+    /// The size and extent of a texel block of `aspect` when copied to or from
+    /// a buffer. Returns `None` if `aspect` is not a single aspect of the
+    /// format that can be copied.
+    pub(crate) fn buffer_texel(
+        self, aspect: ImageAspectFlags,
+    ) -> Option<(u32, Extent3D)> {
+        const ONE: Extent3D = Extent3D { width: 1, height: 1, depth: 1 };
+        if aspect.bits().count_ones() != 1 || !self.aspects().contains(aspect) {
+            return None;
+        }
+        if aspect == ImageAspectFlags::DEPTH {
+            let depth = self.component_bits().depth;
+            return Some((if depth == 16 { 2 } else { 4 }, ONE));
+        }
+        if aspect == ImageAspectFlags::STENCIL {
+            return Some((1, ONE));
+        }
+        if self.is_multi_planar() {
+            if aspect == ImageAspectFlags::COLOR {
+                return None;
+            }
+            let component = self.block_size() / 3;
+            let two_plane = !self.aspects().contains(ImageAspectFlags::PLANE_2);
+            if two_plane && aspect == ImageAspectFlags::PLANE_1 {
+                return Some((component * 2, ONE));
+            }
+            return Some((component, ONE));
+        }
+        Some((self.block_size(), self.block_extent()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn format_info() {
+        let f = vk::Format::R8G8B8A8_SRGB;
+        assert_eq!(f.block_size(), 4);
+        assert!(f.is_srgb() && !f.is_compressed());
+        assert_eq!(f.component_bits().alpha, 8);
+        let bc1 = vk::Format::BC1_RGBA_UNORM_BLOCK;
+        assert_eq!(bc1.block_size(), 8);
+        assert_eq!(bc1.block_extent().width, 4);
+        assert!(bc1.is_compressed());
+        let ds = vk::Format::D24_UNORM_S8_UINT;
+        assert!(ds.is_depth() && ds.is_stencil());
+        assert_eq!(
+            ds.aspects(),
+            vk::ImageAspectFlags::DEPTH | vk::ImageAspectFlags::STENCIL
+        );
+        assert_eq!(
+            ds.buffer_texel(vk::ImageAspectFlags::DEPTH).map(|t| t.0),
+            Some(4)
+        );
+        assert_eq!(
+            vk::Format::R32_UINT.compatibility_class(),
+            vk::Format::R8G8B8A8_UNORM.compatibility_class()
+        );
+        assert_eq!(vk::Format::UNDEFINED.compatibility_class(), None);
+    }
+}
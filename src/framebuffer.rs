@@ -19,6 +19,7 @@ pub struct Framebuffer {
     handle: Handle<VkFramebuffer>,
     _attachments: Vec<Arc<ImageView>>,
     render_pass: Arc<RenderPass>,
+    layers: u32,
 }
 
 impl Framebuffer {
@@ -62,6 +63,7 @@ impl Framebuffer {
             handle: handle.unwrap(),
             _attachments: attachments,
             render_pass: render_pass.clone(),
+            layers: size.depth,
         }))
     }
 
@@ -69,6 +71,10 @@ impl Framebuffer {
     pub fn handle(&self) -> Ref<VkFramebuffer> {
         self.handle.borrow()
     }
+    /// Returns the number of layers.
+    pub fn layers(&self) -> u32 {
+        self.layers
+    }
     /// Returns true if this framebuffer is compatible with `pass`
     pub fn is_compatible_with(&self, pass: &RenderPass) -> bool {
         self.render_pass.compatible(pass)
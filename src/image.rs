@@ -6,10 +6,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use crate::allocator::Allocation;
 use crate::enums::*;
 use crate::error::{Error, ErrorAndSelf, Result, ResultAndSelf};
 use crate::ext::khr_swapchain::SwapchainImages;
-use crate::memory::{DeviceMemory, MemoryLifetime};
+use crate::memory::{AllocateOptions, Dedicated, DeviceMemory, MemoryLifetime};
 use crate::subobject::Subobject;
 use crate::types::*;
 use crate::vk::Device;
@@ -25,7 +26,13 @@ pub struct ImageWithoutMemory {
     extent: Extent3D,
     mip_levels: u32,
     array_layers: u32,
+    samples: SampleCount,
+    tiling: ImageTiling,
+    /// The features of `format` with `tiling`.
+    format_features: FormatFeatureFlags,
     usage: ImageUsageFlags,
+    flags: ImageCreateFlags,
+    external_handle_types: ExternalMemoryHandleTypeFlags,
     res: ImageOwner,
     device: Arc<Device>,
 }
@@ -37,6 +44,7 @@ pub struct ImageWithoutMemory {
 pub struct Image {
     inner: ImageWithoutMemory,
     _memory: Option<Subobject<MemoryLifetime>>,
+    _allocation: Option<Allocation>,
 }
 
 #[derive(Debug)]
@@ -60,9 +68,36 @@ impl std::hash::Hash for Image {
 }
 
 impl ImageWithoutMemory {
+    /// Returns [`Error::InvalidArgument`] if `info.format` is undefined or
+    /// `info.usage`, `info.mip_levels`, or `info.array_layers` is zero.
+    /// Returns [`Error::FormatNotSupported`] if the format does not support
+    /// the image's type, tiling, usage, and flags, and
+    /// [`Error::LimitExceeded`] if the extent, mip levels, array layers, or
+    /// sample count exceed what the format supports.
     #[doc = crate::man_link!(vkCreateImage)]
     pub fn new(
         device: &Arc<Device>, info: &ImageCreateInfo<'_>,
+    ) -> Result<Self> {
+        Self::create(device, info, None)
+    }
+
+    /// Create an image which can be bound to external memory of any of
+    /// `handle_types`. Requires Vulkan 1.1 or VK_KHR_external_memory.
+    #[doc = crate::man_link!(VkExternalMemoryImageCreateInfo)]
+    pub fn new_external(
+        device: &Arc<Device>, info: &ImageCreateInfo<'_>,
+        handle_types: ExternalMemoryHandleTypeFlags,
+    ) -> Result<Self> {
+        let external = ExternalMemoryImageCreateInfo {
+            handle_types,
+            ..Default::default()
+        };
+        Self::create(device, info, Some(&external))
+    }
+
+    fn create(
+        device: &Arc<Device>, info: &ImageCreateInfo<'_>,
+        external: Option<&ExternalMemoryImageCreateInfo>,
     ) -> Result<Self> {
         let max_dim =
             info.extent.width.max(info.extent.height).max(info.extent.depth);
@@ -80,11 +115,52 @@ impl ImageWithoutMemory {
         {
             return Err(Error::LimitExceeded);
         }
+        if info.format == Format::UNDEFINED
+            || info.usage.is_empty()
+            || info.mip_levels == 0
+            || info.array_layers == 0
+        {
+            return Err(Error::InvalidArgument);
+        }
+        let phy = device.physical_device();
+        let props = phy.image_format_properties(
+            info.format,
+            info.image_type,
+            info.tiling,
+            info.usage,
+            info.flags,
+        )?;
+        if info.extent.width > props.max_extent.width
+            || info.extent.height > props.max_extent.height
+            || info.extent.depth > props.max_extent.depth
+            || info.mip_levels > props.max_mip_levels
+            || info.array_layers > props.max_array_layers
+            || !props.sample_counts.contains(info.samples.into())
+        {
+            return Err(Error::LimitExceeded);
+        }
+        let format_features =
+            phy.format_properties(info.format).tiling_features(info.tiling);
         let mut handle = None;
         unsafe {
             (device.fun.create_image)(
                 device.handle(),
-                info,
+                &ImageCreateInfo {
+                    stype: Default::default(),
+                    next: external,
+                    flags: info.flags,
+                    image_type: info.image_type,
+                    format: info.format,
+                    extent: info.extent,
+                    mip_levels: info.mip_levels,
+                    array_layers: info.array_layers,
+                    samples: info.samples,
+                    tiling: info.tiling,
+                    usage: info.usage,
+                    sharing_mode: info.sharing_mode,
+                    queue_family_indices: info.queue_family_indices,
+                    initial_layout: info.initial_layout,
+                },
                 None,
                 &mut handle,
             )?;
@@ -95,7 +171,13 @@ impl ImageWithoutMemory {
             format: info.format,
             mip_levels: info.mip_levels,
             array_layers: info.array_layers,
+            samples: info.samples,
+            tiling: info.tiling,
+            format_features,
             usage: info.usage,
+            flags: info.flags,
+            external_handle_types: external
+                .map_or(Default::default(), |e| e.handle_types),
             res: ImageOwner::Application,
             device: device.clone(),
         })
@@ -108,15 +190,12 @@ impl Image {
     pub fn new(
         image: ImageWithoutMemory, memory: &DeviceMemory, offset: u64,
     ) -> ResultAndSelf<Arc<Self>, ImageWithoutMemory> {
-        assert_eq!(memory.device(), &image.device);
-        if !memory.check(offset, image.memory_requirements()) {
-            return Err(ErrorAndSelf(Error::InvalidArgument, image));
-        }
-        Self::bind_image_impl(image, memory, offset)
+        image.bind_memory(memory, offset)
     }
 
     fn bind_image_impl(
         mut inner: ImageWithoutMemory, memory: &DeviceMemory, offset: u64,
+        allocation: Option<Allocation>,
     ) -> ResultAndSelf<Arc<Self>, ImageWithoutMemory> {
         if let Err(err) = unsafe {
             (memory.device().fun.bind_image_memory)(
@@ -128,7 +207,11 @@ impl Image {
         } {
             return Err(ErrorAndSelf(err.into(), inner));
         }
-        Ok(Arc::new(Self { inner, _memory: Some(memory.resource()) }))
+        Ok(Arc::new(Self {
+            inner,
+            _memory: Some(memory.resource()),
+            _allocation: allocation,
+        }))
     }
 }
 
@@ -147,6 +230,10 @@ impl Drop for ImageWithoutMemory {
 }
 
 impl ImageWithoutMemory {
+    /// Borrows the inner Vulkan handle.
+    pub fn handle(&self) -> Ref<'_, VkImage> {
+        self.handle.borrow()
+    }
     /// Borrows the inner Vulkan handle.
     pub fn mut_handle(&mut self) -> Mut<VkImage> {
         self.handle.borrow_mut()
@@ -167,7 +254,7 @@ impl ImageWithoutMemory {
                 &mut result,
             );
         }
-        if !self.device.enabled().robust_buffer_access.as_bool()
+        if !self.device.enabled().features.robust_buffer_access.as_bool()
             && self.usage.indexable()
         {
             result.clear_host_visible_types(
@@ -176,7 +263,82 @@ impl ImageWithoutMemory {
         }
         result
     }
-    /// Allocate a single piece of memory for the image and bind it.
+    /// Returns whether the implementation prefers or requires the image to
+    /// have its own allocation. If neither Vulkan 1.1 nor
+    /// VK_KHR_get_memory_requirements2 is available, returns that neither is
+    /// the case.
+    #[doc = crate::man_link!(vkGetImageMemoryRequirements2)]
+    pub fn dedicated_requirements(&self) -> MemoryDedicatedRequirements {
+        let mut dedicated = MemoryDedicatedRequirements::default();
+        if let Some(get_requirements2) =
+            self.device.fun.get_image_memory_requirements2
+        {
+            let mut result = MemoryRequirements2 {
+                next: Some(&mut dedicated),
+                ..Default::default()
+            };
+            unsafe {
+                (get_requirements2)(
+                    self.device.handle(),
+                    &ImageMemoryRequirementsInfo2 {
+                        stype: Default::default(),
+                        next: Default::default(),
+                        image: self.handle.borrow(),
+                    },
+                    &mut result,
+                );
+            }
+        }
+        dedicated
+    }
+    /// Bind the image to `memory` at `offset`, which may be shared with other
+    /// buffers and images. The memory is kept alive until the image is
+    /// dropped. Returns [`Error::InvalidArgument`] if the memory does not meet
+    /// the image's [`memory_requirements`](Self::memory_requirements()) at
+    /// `offset`, or if the image would be within
+    /// [`buffer_image_granularity`](PhysicalDeviceLimits::buffer_image_granularity)
+    /// of a resource with different tiling bound to the memory, or if the
+    /// image requires a [dedicated](Self::dedicated_requirements())
+    /// allocation, or if the memory is exportable or imported and the image
+    /// was not created with [`new_external`](Self::new_external()) with a
+    /// matching handle type. Buffers count as linearly tiled. Note that it is
+    /// an error to bind a storage image to host-visible memory when robust
+    /// buffer access is not enabled.
+    ///
+    /// Panics if `memory` belongs to a different device.
+    #[doc = crate::man_link!(vkBindImageMemory)]
+    pub fn bind_memory(
+        self, memory: &DeviceMemory, offset: u64,
+    ) -> ResultAndSelf<Arc<Image>, Self> {
+        assert_eq!(memory.device(), &self.device);
+        let req = self.memory_requirements();
+        let size = req.size;
+        let linear = self.tiling == ImageTiling::LINEAR;
+        let granularity = self.device.limits().buffer_image_granularity;
+        let mut bindings = memory.bindings();
+        if !memory.check(offset, req)
+            || !memory.external_compatible(self.external_handle_types)
+            || !bindings.check(offset, size, linear, granularity)
+            || self
+                .dedicated_requirements()
+                .requires_dedicated_allocation
+                .as_bool()
+        {
+            return Err(ErrorAndSelf(Error::InvalidArgument, self));
+        }
+        let image = Image::bind_image_impl(self, memory, offset, None)?;
+        bindings.push(offset, size, linear, &image);
+        Ok(image)
+    }
+    /// Bind the image to memory from an [`Allocator`](crate::vk::Allocator).
+    pub(crate) fn bind_allocation(
+        self, memory: &DeviceMemory, offset: u64, allocation: Allocation,
+    ) -> ResultAndSelf<Arc<Image>, Self> {
+        Image::bind_image_impl(self, memory, offset, Some(allocation))
+    }
+    /// Allocate a single piece of memory for the image and bind it. The
+    /// memory is a dedicated allocation if the implementation prefers or
+    /// requires it.
     pub fn allocate_memory(
         self, memory_type_index: u32,
     ) -> ResultAndSelf<Arc<Image>, Self> {
@@ -184,25 +346,35 @@ impl ImageWithoutMemory {
         if (1 << memory_type_index) & mem_req.memory_type_bits == 0 {
             return Err(ErrorAndSelf(Error::InvalidArgument, self));
         }
-        let memory = match DeviceMemory::new(
+        let dedicated = self
+            .dedicated_requirements()
+            .dedicated()
+            .then(|| Dedicated::Image(self.handle.borrow()));
+        let memory = match DeviceMemory::allocate(
             &self.device,
             mem_req.size,
             memory_type_index,
+            AllocateOptions { dedicated, ..Default::default() },
         ) {
             Ok(memory) => memory,
             Err(err) => return Err(ErrorAndSelf(err, self)),
         };
         // Don't need to check requirements
-        Image::bind_image_impl(self, &memory, 0)
+        Image::bind_image_impl(self, &memory, 0, None)
     }
 }
 
 impl Image {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_from(
         handle: Handle<VkImage>, device: Arc<Device>,
         res: Subobject<SwapchainImages>, format: Format, extent: Extent3D,
-        array_layers: u32, usage: ImageUsageFlags,
+        array_layers: u32, usage: ImageUsageFlags, flags: ImageCreateFlags,
     ) -> Self {
+        let format_features = device
+            .physical_device()
+            .format_properties(format)
+            .optimal_tiling_features;
         Self {
             inner: ImageWithoutMemory {
                 handle,
@@ -212,9 +384,15 @@ impl Image {
                 extent,
                 array_layers,
                 usage,
+                flags,
+                external_handle_types: Default::default(),
                 mip_levels: 1,
+                samples: SampleCount::_1,
+                tiling: ImageTiling::OPTIMAL,
+                format_features,
             },
             _memory: None,
+            _allocation: None,
         }
     }
 
@@ -234,6 +412,22 @@ impl Image {
     pub fn format(&self) -> Format {
         self.inner.format
     }
+    /// Returns the number of samples per texel of the image.
+    pub fn samples(&self) -> SampleCount {
+        self.inner.samples
+    }
+    /// Returns the flags the image was created with.
+    pub fn flags(&self) -> ImageCreateFlags {
+        self.inner.flags
+    }
+    /// Returns the tiling of the image.
+    pub fn tiling(&self) -> ImageTiling {
+        self.inner.tiling
+    }
+    /// Returns the features supported by the image's format with its tiling.
+    pub fn format_features(&self) -> FormatFeatureFlags {
+        self.inner.format_features
+    }
     /// Returns the extent of the image.
     pub fn extent(&self, mip_level: u32) -> Extent3D {
         let ex = self.inner.extent;
@@ -308,10 +502,38 @@ pub struct ImageViewCreateInfo {
 }
 
 impl ImageView {
-    /// Create an image view of the image.
+    /// Create an image view of the image. Returns
+    /// [`Error::FormatNotSupported`] if the image has `STORAGE` usage and the
+    /// view's format does not support `STORAGE_IMAGE`. Returns
+    /// [`Error::InvalidArgument`] if the view's aspect mask contains aspects
+    /// the image's format does not have, or the view's format differs from the
+    /// image's and either the image was not created with `MUTABLE_FORMAT` or
+    /// the formats are not in the same compatibility class.
     pub fn new(
         image: &Arc<Image>, info: &ImageViewCreateInfo,
     ) -> Result<Arc<Self>> {
+        let aspect_mask = info.subresource_range.aspect_mask;
+        if aspect_mask.is_empty()
+            || !image.format().aspects().contains(aspect_mask)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        if info.format != image.format()
+            && (!image.flags().contains(ImageCreateFlags::MUTABLE_FORMAT)
+                || !view_compatible(image, info.format, aspect_mask))
+        {
+            return Err(Error::InvalidArgument);
+        }
+        if image.usage().contains(ImageUsageFlags::STORAGE)
+            && !image
+                .device()
+                .physical_device()
+                .format_properties(info.format)
+                .tiling_features(image.tiling())
+                .contains(FormatFeatureFlags::STORAGE_IMAGE)
+        {
+            return Err(Error::FormatNotSupported);
+        }
         let vk_info = VkImageViewCreateInfo {
             stype: Default::default(),
             next: Default::default(),
@@ -335,6 +557,27 @@ impl ImageView {
     }
 }
 
+/// Whether a view of `format` can be created of the mutable-format `image`.
+fn view_compatible(
+    image: &Image, format: Format, aspect_mask: ImageAspectFlags,
+) -> bool {
+    let image_format = image.format();
+    if image_format.compatibility_class() == format.compatibility_class() {
+        return true;
+    }
+    // A view of one plane must have a format compatible with that plane.
+    if image_format.is_multi_planar() && aspect_mask != ImageAspectFlags::COLOR
+    {
+        return image_format.buffer_texel(aspect_mask).map(|(size, _)| size)
+            == Some(format.block_size());
+    }
+    // An uncompressed view of a single compressed block.
+    image.flags().contains(ImageCreateFlags::BLOCK_TEXEL_VIEW_COMPATIBLE)
+        && image_format.is_compressed()
+        && !format.is_compressed()
+        && image_format.block_size() == format.block_size()
+}
+
 impl Drop for ImageView {
     fn drop(&mut self) {
         unsafe {
@@ -373,6 +616,8 @@ mod test {
             &dev,
             &ImageCreateInfo {
                 extent: Extent3D { width: 64, height: 64, depth: 1 },
+                format: vk::Format::R8G8B8A8_UNORM,
+                usage: vk::ImageUsageFlags::SAMPLED,
                 ..Default::default()
             },
         )
@@ -397,6 +642,7 @@ mod test {
             &dev,
             &ImageCreateInfo {
                 extent: Extent3D { width: 64, height: 64, depth: 1 },
+                format: vk::Format::R8G8B8A8_UNORM,
                 usage: vk::ImageUsageFlags::STORAGE,
                 ..Default::default()
             },
@@ -414,4 +660,31 @@ mod test {
             .unwrap();
         assert!(buf.allocate_memory(host_mem as u32).is_err());
     }
+    #[test]
+    fn format_limits() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let info = ImageCreateInfo {
+            extent: Extent3D { width: 64, height: 64, depth: 1 },
+            format: vk::Format::R8G8B8A8_UNORM,
+            usage: vk::ImageUsageFlags::SAMPLED,
+            ..Default::default()
+        };
+        assert_eq!(
+            vk::ImageWithoutMemory::new(
+                &dev,
+                &ImageCreateInfo { mip_levels: 32, ..info }
+            )
+            .unwrap_err(),
+            vk::Error::LimitExceeded
+        );
+        assert_eq!(
+            vk::ImageWithoutMemory::new(
+                &dev,
+                &ImageCreateInfo { format: vk::Format::UNDEFINED, ..info }
+            )
+            .unwrap_err(),
+            vk::Error::InvalidArgument
+        );
+        Ok(())
+    }
 }
@@ -7,6 +7,8 @@
 // except according to those terms.
 
 use crate::error::Result;
+use crate::ext::DebugCallback;
+use crate::ffi::{ExtensionChain, NonNull, Str};
 use crate::load;
 use crate::load::InstanceFn;
 use crate::types::*;
@@ -15,6 +17,10 @@ use crate::types::*;
 pub struct Instance {
     handle: Handle<VkInstance>,
     pub(crate) fun: InstanceFn,
+    api_version: u32,
+    enabled_extensions: Vec<String>,
+    // Called by the implementation until the instance is destroyed.
+    _debug_callbacks: Vec<Arc<DebugCallback>>,
 }
 
 impl std::fmt::Debug for Instance {
@@ -30,17 +36,72 @@ impl Drop for Instance {
 }
 
 impl Instance {
-    /// Creates a new instance
+    /// Creates a new instance. If a [`DebugUtilsMessengerCreateInfoEXT`] is
+    /// chained onto `info`, its callback is kept alive until the instance is
+    /// destroyed.
     #[doc = crate::man_link!(vkCreateInstance)]
-    pub fn new<'a>(info: &'a InstanceCreateInfo<'a>) -> Result<Arc<Self>> {
+    pub fn new<'a>(
+        info: &'a InstanceCreateInfo<
+            'a,
+            ExtensionChain<'a, InstanceCreateInfo<'static>>,
+        >,
+    ) -> Result<Arc<Self>> {
         let mut handle = None;
         unsafe { (load::vk_create_instance())(info, None, &mut handle)? };
         let handle = handle.unwrap();
         let fun = InstanceFn::new(handle.borrow());
-        Ok(Arc::new(Instance { handle, fun }))
+        let api_version = match info.application_info {
+            Some(app) if app.api_version != 0 => app.api_version,
+            _ => 1 << 22,
+        };
+        let enabled_extensions = info
+            .enabled_extension_names
+            .into_iter()
+            .map(|name| name.as_str().to_owned())
+            .collect();
+        let mut debug_callbacks = vec![];
+        let mut next = info.next.head().map(NonNull::cast::<BaseInStructure>);
+        while let Some(ptr) = next {
+            // Safety: Guaranteed by the contract of Extends.
+            unsafe {
+                if ptr.as_ref().stype
+                    == DebugUtilsMessengerCreateInfoEXTType::Value as u32
+                {
+                    let debug = ptr.cast::<DebugUtilsMessengerCreateInfoEXT>();
+                    debug_callbacks.push(debug.as_ref().callback.clone());
+                }
+                next = ptr.as_ref().next;
+            }
+        }
+        Ok(Arc::new(Instance {
+            handle,
+            fun,
+            api_version,
+            enabled_extensions,
+            _debug_callbacks: debug_callbacks,
+        }))
     }
     /// Borrows the inner Vulkan handle.
     pub fn handle(&self) -> Ref<VkInstance> {
         self.handle.borrow()
     }
+    /// Returns the API version the instance was created with, which is 1.0 if
+    /// none was specified.
+    pub fn api_version(&self) -> u32 {
+        self.api_version
+    }
+    /// Returns true if the instance extension `name` was enabled when the
+    /// instance was created.
+    pub fn is_extension_enabled(&self, name: Str<'_>) -> bool {
+        self.enabled_extensions.iter().any(|e| e == name.as_str())
+    }
+    /// Returns true if the vkGetPhysicalDevice*2 functions may be called,
+    /// either because the instance version is at least 1.1 or because
+    /// VK_KHR_get_physical_device_properties2 is enabled.
+    pub(crate) fn has_properties2(&self) -> bool {
+        self.api_version >= crate::physical_device::VERSION_1_1
+            || self.is_extension_enabled(
+                crate::ext::GET_PHYSICAL_DEVICE_PROPERTIES2,
+            )
+    }
 }
@@ -14,6 +14,7 @@
 #![doc = include_str!("../hello-triangle/src/main.rs")]
 #![doc = "```"]
 
+mod allocator;
 mod instance;
 mod physical_device;
 mod device;
@@ -26,14 +27,20 @@ mod command_buffer;
 mod descriptor_set;
 mod enums;
 mod error;
+mod event;
 mod exclusive;
 mod fence;
+mod format;
+#[cfg(feature = "async")]
+mod fence_future;
 mod semaphore;
 mod ffi;
 mod framebuffer;
 mod load;
 mod shader;
 mod pipeline;
+mod profiler;
+mod query_pool;
 mod render_pass;
 mod sampler;
 mod subobject;
@@ -46,6 +53,7 @@ pub mod ext;
 pub mod macos_instructions;
 
 use crate::error::Result;
+use crate::ffi::Str;
 use crate::types::*;
 
 macro_rules! man_link{
@@ -71,14 +79,47 @@ pub(crate) use spec_link;
 
 #[doc = crate::man_link!(vkEnumerateInstanceExtensionProperties)]
 pub fn instance_extension_properties() -> Result<Vec<ExtensionProperties>> {
+    instance_extension_properties_impl(None)
+}
+
+/// Returns the instance extensions provided by the layer `layer_name`.
+#[doc = crate::man_link!(vkEnumerateInstanceExtensionProperties)]
+pub fn instance_layer_extension_properties(
+    layer_name: Str<'_>,
+) -> Result<Vec<ExtensionProperties>> {
+    instance_extension_properties_impl(Some(layer_name))
+}
+
+fn instance_extension_properties_impl(
+    layer_name: Option<Str<'_>>,
+) -> Result<Vec<ExtensionProperties>> {
     let mut len = 0;
     let mut result = Vec::new();
     unsafe {
         let fn_ptr = load::vk_enumerate_instance_extension_properties();
-        fn_ptr(None, &mut len, None)?;
+        fn_ptr(layer_name, &mut len, None)?;
+        result.reserve(len as usize);
+        fn_ptr(
+            layer_name,
+            &mut len,
+            ffi::ArrayMut::from_slice(result.spare_capacity_mut()),
+        )?;
+        result.set_len(len as usize);
+    }
+    Ok(result)
+}
+
+/// Returns the available instance layers. A layer can be enabled by adding
+/// its name to [`InstanceCreateInfo::enabled_layer_names`].
+#[doc = crate::man_link!(vkEnumerateInstanceLayerProperties)]
+pub fn instance_layer_properties() -> Result<Vec<LayerProperties>> {
+    let mut len = 0;
+    let mut result = Vec::new();
+    unsafe {
+        let fn_ptr = load::vk_enumerate_instance_layer_properties();
+        fn_ptr(&mut len, None)?;
         result.reserve(len as usize);
         fn_ptr(
-            None,
             &mut len,
             ffi::ArrayMut::from_slice(result.spare_capacity_mut()),
         )?;
@@ -87,6 +128,19 @@ pub fn instance_extension_properties() -> Result<Vec<ExtensionProperties>> {
     Ok(result)
 }
 
+/// Returns the highest API version supported by the loader for instances.
+/// Returns version 1.0 if the loader only supports Vulkan 1.0.
+#[doc = crate::man_link!(vkEnumerateInstanceVersion)]
+pub fn instance_version() -> Result<u32> {
+    let mut version = 1 << 22;
+    unsafe {
+        if let Some(fn_ptr) = load::vk_enumerate_instance_version() {
+            fn_ptr(&mut version)?;
+        }
+    }
+    Ok(version)
+}
+
 #[cfg(test)]
 pub(crate) fn test_device() -> Result<(Arc<device::Device>, queue::Queue)> {
     let inst = vk::Instance::new(&Default::default())?;
@@ -107,10 +161,37 @@ pub(crate) fn test_device() -> Result<(Arc<device::Device>, queue::Queue)> {
     Ok((dev, qs.remove(0).remove(0)))
 }
 
+/// A Vulkan 1.1 device with `extensions` enabled.
+#[cfg(test)]
+pub(crate) fn test_device_with_extensions(
+    extensions: &[ffi::Str<'static>],
+) -> Result<(Arc<device::Device>, queue::Queue)> {
+    let inst = vk::Instance::new(&vk::InstanceCreateInfo {
+        application_info: Some(&vk::ApplicationInfo {
+            api_version: 1 << 22 | 1 << 12,
+            ..Default::default()
+        }),
+        ..Default::default()
+    })?;
+    let (dev, mut qs) = vk::Device::new(
+        &inst.enumerate_physical_devices()?[0],
+        &vk::DeviceCreateInfo {
+            queue_create_infos: vk::slice(&[vk::DeviceQueueCreateInfo {
+                queue_priorities: vk::slice(&[1.0]),
+                ..Default::default()
+            }]),
+            enabled_extension_names: vk::slice(extensions),
+            ..Default::default()
+        },
+    )?;
+    Ok((dev, qs.remove(0).remove(0)))
+}
+
 /// Vulkan core functionality.
 ///
 /// This module is intended to be imported qualified; ie `use maia::vk;`
 pub mod vk {
+    pub use crate::allocator::{Allocator, MemoryUsage};
     pub use crate::buffer::{Buffer, BufferWithoutMemory};
     pub use crate::command_buffer::barrier::{
         BufferMemoryBarrier, ImageMemoryBarrier,
@@ -126,34 +207,64 @@ pub mod vk {
         DescriptorPool, DescriptorSet, DescriptorSetLayout,
         DescriptorSetLayoutBinding,
     };
-    pub use crate::device::Device;
+    pub use crate::device::{Device, DeviceCreateInfoNext};
     pub use crate::enums::Bool::{False, True};
     pub use crate::enums::*;
     pub use crate::error::{Error, ErrorAndSelf, Result, ResultAndSelf};
+    pub use crate::event::Event;
     pub use crate::ext;
     pub use crate::ext::khr_swapchain::{
         CreateSwapchainFrom, SwapchainCreateInfoKHR,
     };
     pub use crate::fence::{Fence, PendingFence};
+    #[cfg(feature = "async")]
+    pub use crate::fence_future::FenceFuture;
     pub use crate::ffi::*;
+    pub use crate::format::{CompatibilityClass, ComponentBits, NumericType};
     pub use crate::framebuffer::Framebuffer;
     pub use crate::image::{
         Image, ImageView, ImageViewCreateInfo, ImageWithoutMemory,
     };
     pub use crate::instance::Instance;
     pub use crate::instance_extension_properties;
+    pub use crate::instance_layer_extension_properties;
+    pub use crate::instance_layer_properties;
+    pub use crate::instance_version;
     pub use crate::memory::{
-        DeviceMemory, MappedMemory, MemoryRead, MemoryWrite,
+        DeviceMemory, HostAllocation, MappedMemory, MemoryRead, MemoryWrite,
+    };
+    pub use crate::physical_device::{
+        DeviceFeatures, DeviceProperties, FeatureNames, PhysicalDevice,
     };
-    pub use crate::physical_device::PhysicalDevice;
     pub use crate::pipeline::{
         GraphicsPipelineCreateInfo, Pipeline, PipelineCache, PipelineLayout,
     };
+    pub use crate::profiler::{FrameTimings, Profiler, ScopeTiming};
+    pub use crate::query_pool::QueryPool;
     pub use crate::queue::Queue;
     pub use crate::queue::SubmitInfo;
     pub use crate::render_pass::RenderPass;
     pub use crate::sampler::Sampler;
-    pub use crate::semaphore::Semaphore;
+    pub use crate::semaphore::{Semaphore, TimelineSemaphore};
     pub use crate::shader::ShaderModule;
     pub use crate::types::*;
 }
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn layers() -> vk::Result<()> {
+        assert!(vk::instance_version()? >= 1 << 22);
+        let inst = vk::Instance::new(&Default::default())?;
+        let phy = inst.enumerate_physical_devices()?.remove(0);
+        for layer in vk::instance_layer_properties()? {
+            let name = format!("{}\0", layer.layer_name.as_str());
+            let name = vk::Str::new(&name).unwrap();
+            vk::instance_layer_extension_properties(name)?;
+            phy.device_layer_extension_properties(name)?;
+        }
+        Ok(())
+    }
+}
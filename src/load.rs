@@ -24,7 +24,7 @@ extern "system" {
 }
 
 pub unsafe fn vk_create_instance() -> unsafe extern "system" fn(
-    &'_ InstanceCreateInfo<'_>,
+    &'_ InstanceCreateInfo<'_, ExtensionChain<'_, InstanceCreateInfo<'static>>>,
     Option<&'_ AllocationCallbacks>,
     &mut Option<Handle<VkInstance>>,
 ) -> VkResult {
@@ -40,6 +40,30 @@ pub unsafe fn vk_enumerate_instance_extension_properties(
     transmute(load(None, "vkEnumerateInstanceExtensionProperties\0"))
 }
 
+pub unsafe fn vk_enumerate_instance_layer_properties(
+) -> unsafe extern "system" fn(
+    &mut u32,
+    Option<ArrayMut<MaybeUninit<LayerProperties>>>,
+) -> VkResult {
+    transmute::<
+        NonNull<c_void>,
+        unsafe extern "system" fn(
+            &mut u32,
+            Option<ArrayMut<MaybeUninit<LayerProperties>>>,
+        ) -> VkResult,
+    >(load(None, "vkEnumerateInstanceLayerProperties\0"))
+}
+
+/// Returns None on Vulkan 1.0, where the function does not exist.
+pub unsafe fn vk_enumerate_instance_version(
+) -> Option<unsafe extern "system" fn(&mut u32) -> VkResult> {
+    transmute::<
+        Option<NonNull<c_void>>,
+        Option<unsafe extern "system" fn(&mut u32) -> VkResult>,
+    >(try_load(None, "vkEnumerateInstanceVersion\0"))
+}
+
+#[allow(clippy::type_complexity)]
 pub struct InstanceFn {
     pub destroy_instance: unsafe extern "system" fn(
         Mut<VkInstance>,
@@ -50,6 +74,25 @@ pub struct InstanceFn {
         &mut u32,
         Option<ArrayMut<MaybeUninit<Handle<VkPhysicalDevice>>>>,
     ) -> VkResult,
+    pub get_physical_device_features: unsafe extern "system" fn(
+        Ref<VkPhysicalDevice>,
+        &mut PhysicalDeviceFeatures,
+    ),
+    pub get_physical_device_format_properties: unsafe extern "system" fn(
+        Ref<VkPhysicalDevice>,
+        Format,
+        &mut FormatProperties,
+    ),
+    pub get_physical_device_image_format_properties:
+        unsafe extern "system" fn(
+            Ref<VkPhysicalDevice>,
+            Format,
+            ImageType,
+            ImageTiling,
+            ImageUsageFlags,
+            ImageCreateFlags,
+            &mut ImageFormatProperties,
+        ) -> VkResult,
     pub get_physical_device_properties: unsafe extern "system" fn(
         Ref<VkPhysicalDevice>,
         &mut MaybeUninit<PhysicalDeviceProperties>,
@@ -73,7 +116,7 @@ pub struct InstanceFn {
         ) -> VkResult,
     pub create_device: unsafe extern "system" fn(
         Ref<VkPhysicalDevice>,
-        &'_ DeviceCreateInfo,
+        &'_ DeviceCreateInfo<'_, Option<NonNull<c_void>>>,
         Option<&'_ AllocationCallbacks>,
         &mut Option<Handle<VkDevice>>,
     ) -> VkResult,
@@ -82,44 +125,162 @@ pub struct InstanceFn {
             Ref<VkDevice>,
             name: Str<'_>,
         ) -> Option<NonNull<c_void>>,
+    /// Requires an instance with Vulkan 1.1 or
+    /// VK_KHR_get_physical_device_properties2 enabled. The pointer may be
+    /// non-null even if neither is the case. The extension structures are
+    /// chained by the caller.
+    pub get_physical_device_features2: Option<
+        unsafe extern "system" fn(
+            Ref<VkPhysicalDevice>,
+            &mut PhysicalDeviceFeatures2<Option<NonNull<c_void>>>,
+        ),
+    >,
+    /// Requires an instance with Vulkan 1.1 or
+    /// VK_KHR_get_physical_device_properties2 enabled. The pointer may be
+    /// non-null even if neither is the case. The extension structures are
+    /// chained by the caller.
+    pub get_physical_device_properties2: Option<
+        unsafe extern "system" fn(
+            Ref<VkPhysicalDevice>,
+            &mut MaybeUninit<PhysicalDeviceProperties2<Option<NonNull<c_void>>>>,
+        ),
+    >,
+    /// Requires an instance with Vulkan 1.1 or
+    /// VK_KHR_get_physical_device_properties2 enabled. The pointer may be
+    /// non-null even if neither is the case.
+    pub get_physical_device_memory_properties2: Option<
+        unsafe extern "system" fn(
+            Ref<VkPhysicalDevice>,
+            &mut PhysicalDeviceMemoryProperties2<
+                Option<&mut PhysicalDeviceMemoryBudgetPropertiesEXT>,
+            >,
+        ),
+    >,
 }
 
 impl InstanceFn {
     pub fn new(inst: Ref<VkInstance>) -> Self {
-        let inst = Some(inst);
-        unsafe {
-            Self {
-                destroy_instance: transmute(load(inst, "vkDestroyInstance\0")),
-                create_device: transmute(load(inst, "vkCreateDevice\0")),
-                get_physical_device_properties: transmute(load(
-                    inst,
-                    "vkGetPhysicalDeviceProperties\0",
-                )),
-                get_physical_device_queue_family_properties: transmute(load(
-                    inst,
-                    "vkGetPhysicalDeviceQueueFamilyProperties\0",
-                )),
-                get_physical_device_memory_properties: transmute(load(
-                    inst,
-                    "vkGetPhysicalDeviceMemoryProperties\0",
-                )),
-                enumerate_device_extension_properties: transmute(load(
-                    inst,
-                    "vkEnumerateDeviceExtensionProperties\0",
-                )),
-                get_device_proc_addr: transmute(load(
-                    inst,
-                    "vkGetDeviceProcAddr\0",
-                )),
-                enumerate_physical_devices: transmute(load(
-                    inst,
-                    "vkEnumeratePhysicalDevices\0",
-                )),
-            }
-        }
+        unsafe { new_instance_fn(inst) }
     }
 }
 
+// Reduce indent
+unsafe fn new_instance_fn(inst: Ref<VkInstance>) -> InstanceFn {
+    let inst = Some(inst);
+    InstanceFn {
+        destroy_instance: transmute(load(inst, "vkDestroyInstance\0")),
+        create_device: transmute(load(inst, "vkCreateDevice\0")),
+        get_physical_device_features: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkPhysicalDevice>,
+                &mut PhysicalDeviceFeatures,
+            ),
+        >(load(
+            inst,
+            "vkGetPhysicalDeviceFeatures\0",
+        )),
+        get_physical_device_format_properties: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkPhysicalDevice>,
+                Format,
+                &mut FormatProperties,
+            ),
+        >(load(
+            inst,
+            "vkGetPhysicalDeviceFormatProperties\0",
+        )),
+        get_physical_device_image_format_properties: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkPhysicalDevice>,
+                Format,
+                ImageType,
+                ImageTiling,
+                ImageUsageFlags,
+                ImageCreateFlags,
+                &mut ImageFormatProperties,
+            ) -> VkResult,
+        >(load(
+            inst,
+            "vkGetPhysicalDeviceImageFormatProperties\0",
+        )),
+        get_physical_device_properties: transmute(load(
+            inst,
+            "vkGetPhysicalDeviceProperties\0",
+        )),
+        get_physical_device_queue_family_properties: transmute(load(
+            inst,
+            "vkGetPhysicalDeviceQueueFamilyProperties\0",
+        )),
+        get_physical_device_memory_properties: transmute(load(
+            inst,
+            "vkGetPhysicalDeviceMemoryProperties\0",
+        )),
+        enumerate_device_extension_properties: transmute(load(
+            inst,
+            "vkEnumerateDeviceExtensionProperties\0",
+        )),
+        get_device_proc_addr: transmute(load(inst, "vkGetDeviceProcAddr\0")),
+        enumerate_physical_devices: transmute(load(
+            inst,
+            "vkEnumeratePhysicalDevices\0",
+        )),
+        get_physical_device_features2: transmute::<
+            Option<NonNull<c_void>>,
+            Option<
+                unsafe extern "system" fn(
+                    Ref<VkPhysicalDevice>,
+                    &mut PhysicalDeviceFeatures2<Option<NonNull<c_void>>>,
+                ),
+            >,
+        >(
+            try_load(inst, "vkGetPhysicalDeviceFeatures2\0").or_else(|| {
+                try_load(inst, "vkGetPhysicalDeviceFeatures2KHR\0")
+            }),
+        ),
+        get_physical_device_properties2: transmute::<
+            Option<NonNull<c_void>>,
+            Option<
+                unsafe extern "system" fn(
+                    Ref<VkPhysicalDevice>,
+                    &mut MaybeUninit<
+                        PhysicalDeviceProperties2<Option<NonNull<c_void>>>,
+                    >,
+                ),
+            >,
+        >(
+            try_load(inst, "vkGetPhysicalDeviceProperties2\0").or_else(|| {
+                try_load(inst, "vkGetPhysicalDeviceProperties2KHR\0")
+            }),
+        ),
+        get_physical_device_memory_properties2: transmute::<
+            Option<NonNull<c_void>>,
+            Option<
+                unsafe extern "system" fn(
+                    Ref<VkPhysicalDevice>,
+                    &mut PhysicalDeviceMemoryProperties2<
+                        Option<&mut PhysicalDeviceMemoryBudgetPropertiesEXT>,
+                    >,
+                ),
+            >,
+        >(
+            try_load(inst, "vkGetPhysicalDeviceMemoryProperties2\0").or_else(
+                || try_load(inst, "vkGetPhysicalDeviceMemoryProperties2KHR\0"),
+            ),
+        ),
+    }
+}
+
+/// Load instance function. Returns None if the function was not found, for
+/// functions that depend on the API version or an extension.
+fn try_load(
+    instance: Option<Ref<VkInstance>>, name: &str,
+) -> Option<NonNull<c_void>> {
+    unsafe { vkGetInstanceProcAddr(instance, name.try_into().unwrap()) }
+}
+
 /// Load instance function. Panics if the string is not null-terminated or the
 /// function was not found.
 fn load(instance: Option<Ref<VkInstance>>, name: &str) -> NonNull<c_void> {
@@ -150,13 +311,14 @@ pub struct DeviceFn {
     pub queue_submit: unsafe extern "system" fn(
         Mut<VkQueue>,
         u32,
-        Option<Array<VkSubmitInfo<Null>>>,
+        Option<Array<VkSubmitInfo<Option<&TimelineSemaphoreSubmitInfo>>>>,
         Option<Mut<VkFence>>,
     ) -> VkResult,
     pub queue_wait_idle: unsafe extern "system" fn(Mut<VkQueue>) -> VkResult,
+    /// The extension structures are chained by the caller.
     pub allocate_memory: unsafe extern "system" fn(
         Ref<VkDevice>,
-        &MemoryAllocateInfo,
+        &MemoryAllocateInfo<Option<NonNull<c_void>>>,
         Option<&'_ AllocationCallbacks>,
         &mut Option<Handle<VkDeviceMemory>>,
     ) -> VkResult,
@@ -170,6 +332,17 @@ pub struct DeviceFn {
     ) -> VkResult,
     pub unmap_memory:
         unsafe extern "system" fn(Ref<VkDevice>, Mut<VkDeviceMemory>),
+    pub flush_mapped_memory_ranges: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        u32,
+        Array<MappedMemoryRange>,
+    ) -> VkResult,
+    pub invalidate_mapped_memory_ranges: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        u32,
+        Array<MappedMemoryRange>,
+    )
+        -> VkResult,
     pub free_memory: unsafe extern "system" fn(
         Ref<VkDevice>,
         Mut<VkDeviceMemory>,
@@ -177,7 +350,7 @@ pub struct DeviceFn {
     ),
     pub create_fence: unsafe extern "system" fn(
         Ref<VkDevice>,
-        &FenceCreateInfo,
+        &FenceCreateInfo<Option<&ExportFenceCreateInfo>>,
         Option<&'_ AllocationCallbacks>,
         &mut Option<Handle<VkFence>>,
     ) -> VkResult,
@@ -198,9 +371,29 @@ pub struct DeviceFn {
         u32,
         Array<Mut<VkFence>>,
     ) -> VkResult,
+    pub get_fence_status:
+        unsafe extern "system" fn(Ref<VkDevice>, Ref<VkFence>) -> VkResult,
+    pub create_event: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        &EventCreateInfo,
+        Option<&'_ AllocationCallbacks>,
+        &mut Option<Handle<VkEvent>>,
+    ) -> VkResult,
+    pub destroy_event: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        Mut<VkEvent>,
+        Option<&'_ AllocationCallbacks>,
+    ),
+    pub get_event_status:
+        unsafe extern "system" fn(Ref<VkDevice>, Ref<VkEvent>) -> VkResult,
+    pub set_event:
+        unsafe extern "system" fn(Ref<VkDevice>, Mut<VkEvent>) -> VkResult,
+    pub reset_event:
+        unsafe extern "system" fn(Ref<VkDevice>, Mut<VkEvent>) -> VkResult,
     pub create_semaphore: unsafe extern "system" fn(
         Ref<VkDevice>,
-        &SemaphoreCreateInfo,
+        // The extension structures are chained by the caller.
+        &SemaphoreCreateInfo<Option<NonNull<c_void>>>,
         Option<&'_ AllocationCallbacks>,
         &mut Option<Handle<VkSemaphore>>,
     ) -> VkResult,
@@ -211,7 +404,7 @@ pub struct DeviceFn {
     ),
     pub create_buffer: unsafe extern "system" fn(
         Ref<VkDevice>,
-        &BufferCreateInfo,
+        &BufferCreateInfo<Option<&ExternalMemoryBufferCreateInfo>>,
         Option<&'_ AllocationCallbacks>,
         &mut Option<Handle<VkBuffer>>,
     ) -> VkResult,
@@ -222,7 +415,7 @@ pub struct DeviceFn {
     ),
     pub create_image: unsafe extern "system" fn(
         Ref<VkDevice>,
-        &ImageCreateInfo,
+        &ImageCreateInfo<Option<&ExternalMemoryImageCreateInfo>>,
         Option<&'_ AllocationCallbacks>,
         &mut Option<Handle<VkImage>>,
     ) -> VkResult,
@@ -241,6 +434,22 @@ pub struct DeviceFn {
         Ref<VkImage>,
         &mut MemoryRequirements,
     ),
+    /// Requires Vulkan 1.1 or VK_KHR_get_memory_requirements2.
+    pub get_buffer_memory_requirements2: Option<
+        unsafe extern "system" fn(
+            Ref<VkDevice>,
+            &BufferMemoryRequirementsInfo2,
+            &mut MemoryRequirements2<Option<&mut MemoryDedicatedRequirements>>,
+        ),
+    >,
+    /// Requires Vulkan 1.1 or VK_KHR_get_memory_requirements2.
+    pub get_image_memory_requirements2: Option<
+        unsafe extern "system" fn(
+            Ref<VkDevice>,
+            &ImageMemoryRequirementsInfo2,
+            &mut MemoryRequirements2<Option<&mut MemoryDedicatedRequirements>>,
+        ),
+    >,
     pub bind_buffer_memory: unsafe extern "system" fn(
         Ref<VkDevice>,
         Mut<VkBuffer>,
@@ -437,6 +646,13 @@ pub struct DeviceFn {
         u64,
         u32,
     ),
+    pub cmd_update_buffer: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkBuffer>,
+        u64,
+        u64,
+        Array<u8>,
+    ),
     pub cmd_copy_buffer: unsafe extern "system" fn(
         Mut<VkCommandBuffer>,
         Ref<VkBuffer>,
@@ -452,6 +668,23 @@ pub struct DeviceFn {
         u32,
         Array<BufferImageCopy>,
     ),
+    pub cmd_copy_image_to_buffer: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkImage>,
+        ImageLayout,
+        Ref<VkBuffer>,
+        u32,
+        Array<BufferImageCopy>,
+    ),
+    pub cmd_copy_image: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkImage>,
+        ImageLayout,
+        Ref<VkImage>,
+        ImageLayout,
+        u32,
+        Array<ImageCopy>,
+    ),
     pub cmd_blit_image: unsafe extern "system" fn(
         Mut<VkCommandBuffer>,
         Ref<VkImage>,
@@ -462,6 +695,15 @@ pub struct DeviceFn {
         Array<ImageBlit>,
         Filter,
     ),
+    pub cmd_resolve_image: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkImage>,
+        ImageLayout,
+        Ref<VkImage>,
+        ImageLayout,
+        u32,
+        Array<ImageResolve>,
+    ),
     pub cmd_clear_color_image: unsafe extern "system" fn(
         Mut<VkCommandBuffer>,
         Ref<VkImage>,
@@ -470,6 +712,21 @@ pub struct DeviceFn {
         u32,
         Array<ImageSubresourceRange>,
     ),
+    pub cmd_clear_depth_stencil_image: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkImage>,
+        ImageLayout,
+        &ClearDepthStencilValue,
+        u32,
+        Array<ImageSubresourceRange>,
+    ),
+    pub cmd_clear_attachments: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        u32,
+        Array<ClearAttachment>,
+        u32,
+        Array<ClearRect>,
+    ),
     pub cmd_pipeline_barrier: unsafe extern "system" fn(
         Mut<VkCommandBuffer>,
         PipelineStageFlags,
@@ -482,6 +739,29 @@ pub struct DeviceFn {
         u32,
         Option<Array<VkImageMemoryBarrier>>,
     ),
+    pub cmd_set_event: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkEvent>,
+        PipelineStageFlags,
+    ),
+    pub cmd_reset_event: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkEvent>,
+        PipelineStageFlags,
+    ),
+    pub cmd_wait_events: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        u32,
+        Array<Ref<VkEvent>>,
+        PipelineStageFlags,
+        PipelineStageFlags,
+        u32,
+        Option<Array<MemoryBarrier>>,
+        u32,
+        Option<Array<VkBufferMemoryBarrier>>,
+        u32,
+        Option<Array<VkImageMemoryBarrier>>,
+    ),
     pub cmd_begin_render_pass: unsafe extern "system" fn(
         Mut<VkCommandBuffer>,
         &RenderPassBeginInfo,
@@ -571,11 +851,63 @@ pub struct DeviceFn {
         unsafe extern "system" fn(Mut<VkCommandBuffer>, u32, u32, u32),
     pub cmd_dispatch_indirect:
         unsafe extern "system" fn(Mut<VkCommandBuffer>, Ref<VkBuffer>, u64),
+    pub create_query_pool: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        &QueryPoolCreateInfo,
+        Option<&'_ AllocationCallbacks>,
+        &mut Option<Handle<VkQueryPool>>,
+    ) -> VkResult,
+    pub destroy_query_pool: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        Mut<VkQueryPool>,
+        Option<&'_ AllocationCallbacks>,
+    ),
+    pub get_query_pool_results: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        Ref<VkQueryPool>,
+        u32,
+        u32,
+        usize,
+        NonNull<c_void>,
+        u64,
+        QueryResultFlags,
+    ) -> VkResult,
+    pub cmd_begin_query: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkQueryPool>,
+        u32,
+        QueryControlFlags,
+    ),
+    pub cmd_end_query:
+        unsafe extern "system" fn(Mut<VkCommandBuffer>, Ref<VkQueryPool>, u32),
+    pub cmd_reset_query_pool: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkQueryPool>,
+        u32,
+        u32,
+    ),
+    pub cmd_write_timestamp: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        PipelineStageFlags,
+        Ref<VkQueryPool>,
+        u32,
+    ),
+    pub cmd_copy_query_pool_results: unsafe extern "system" fn(
+        Mut<VkCommandBuffer>,
+        Ref<VkQueryPool>,
+        u32,
+        u32,
+        Ref<VkBuffer>,
+        u64,
+        u64,
+        QueryResultFlags,
+    ),
 }
 
 // Reduce indent
 unsafe fn new_device_fn(inst: &Instance, device: Ref<VkDevice>) -> DeviceFn {
     let load = |name| inst.load(device, name);
+    let try_load = |name| inst.try_load(device, name);
     DeviceFn {
         destroy_device: transmute(load("vkDestroyDevice\0")),
         device_wait_idle: transmute(load("vkDeviceWaitIdle\0")),
@@ -585,11 +917,64 @@ unsafe fn new_device_fn(inst: &Instance, device: Ref<VkDevice>) -> DeviceFn {
         allocate_memory: transmute(load("vkAllocateMemory\0")),
         map_memory: transmute(load("vkMapMemory\0")),
         unmap_memory: transmute(load("vkUnmapMemory\0")),
+        flush_mapped_memory_ranges: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                u32,
+                Array<MappedMemoryRange>,
+            ) -> VkResult,
+        >(load(
+            "vkFlushMappedMemoryRanges\0",
+        )),
+        invalidate_mapped_memory_ranges: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                u32,
+                Array<MappedMemoryRange>,
+            ) -> VkResult,
+        >(load(
+            "vkInvalidateMappedMemoryRanges\0",
+        )),
         free_memory: transmute(load("vkFreeMemory\0")),
         create_fence: transmute(load("vkCreateFence\0")),
         destroy_fence: transmute(load("vkDestroyFence\0")),
         wait_for_fences: transmute(load("vkWaitForFences\0")),
         reset_fences: transmute(load("vkResetFences\0")),
+        get_fence_status: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(Ref<VkDevice>, Ref<VkFence>) -> VkResult,
+        >(load("vkGetFenceStatus\0")),
+        create_event: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                &EventCreateInfo,
+                Option<&'_ AllocationCallbacks>,
+                &mut Option<Handle<VkEvent>>,
+            ) -> VkResult,
+        >(load("vkCreateEvent\0")),
+        destroy_event: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                Mut<VkEvent>,
+                Option<&'_ AllocationCallbacks>,
+            ),
+        >(load("vkDestroyEvent\0")),
+        get_event_status: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(Ref<VkDevice>, Ref<VkEvent>) -> VkResult,
+        >(load("vkGetEventStatus\0")),
+        set_event: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(Ref<VkDevice>, Mut<VkEvent>) -> VkResult,
+        >(load("vkSetEvent\0")),
+        reset_event: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(Ref<VkDevice>, Mut<VkEvent>) -> VkResult,
+        >(load("vkResetEvent\0")),
         create_semaphore: transmute(load("vkCreateSemaphore\0")),
         destroy_semaphore: transmute(load("vkDestroySemaphore\0")),
         create_buffer: transmute(load("vkCreateBuffer\0")),
@@ -602,6 +987,36 @@ unsafe fn new_device_fn(inst: &Instance, device: Ref<VkDevice>) -> DeviceFn {
         get_image_memory_requirements: transmute(load(
             "vkGetImageMemoryRequirements\0",
         )),
+        get_buffer_memory_requirements2: transmute::<
+            Option<NonNull<c_void>>,
+            Option<
+                unsafe extern "system" fn(
+                    Ref<VkDevice>,
+                    &BufferMemoryRequirementsInfo2,
+                    &mut MemoryRequirements2<
+                        Option<&mut MemoryDedicatedRequirements>,
+                    >,
+                ),
+            >,
+        >(
+            try_load("vkGetBufferMemoryRequirements2\0")
+                .or_else(|| try_load("vkGetBufferMemoryRequirements2KHR\0")),
+        ),
+        get_image_memory_requirements2: transmute::<
+            Option<NonNull<c_void>>,
+            Option<
+                unsafe extern "system" fn(
+                    Ref<VkDevice>,
+                    &ImageMemoryRequirementsInfo2,
+                    &mut MemoryRequirements2<
+                        Option<&mut MemoryDedicatedRequirements>,
+                    >,
+                ),
+            >,
+        >(
+            try_load("vkGetImageMemoryRequirements2\0")
+                .or_else(|| try_load("vkGetImageMemoryRequirements2KHR\0")),
+        ),
         bind_buffer_memory: transmute(load("vkBindBufferMemory\0")),
         bind_image_memory: transmute(load("vkBindImageMemory\0")),
         create_image_view: transmute(load("vkCreateImageView\0")),
@@ -643,11 +1058,111 @@ unsafe fn new_device_fn(inst: &Instance, device: Ref<VkDevice>) -> DeviceFn {
         begin_command_buffer: transmute(load("vkBeginCommandBuffer\0")),
         end_command_buffer: transmute(load("vkEndCommandBuffer\0")),
         cmd_fill_buffer: transmute(load("vkCmdFillBuffer\0")),
+        cmd_update_buffer: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkBuffer>,
+                u64,
+                u64,
+                Array<u8>,
+            ),
+        >(load("vkCmdUpdateBuffer\0")),
         cmd_copy_buffer: transmute(load("vkCmdCopyBuffer\0")),
         cmd_copy_buffer_to_image: transmute(load("vkCmdCopyBufferToImage\0")),
+        cmd_copy_image_to_buffer: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkImage>,
+                ImageLayout,
+                Ref<VkBuffer>,
+                u32,
+                Array<BufferImageCopy>,
+            ),
+        >(load("vkCmdCopyImageToBuffer\0")),
+        cmd_copy_image: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkImage>,
+                ImageLayout,
+                Ref<VkImage>,
+                ImageLayout,
+                u32,
+                Array<ImageCopy>,
+            ),
+        >(load("vkCmdCopyImage\0")),
         cmd_blit_image: transmute(load("vkCmdBlitImage\0")),
+        cmd_resolve_image: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkImage>,
+                ImageLayout,
+                Ref<VkImage>,
+                ImageLayout,
+                u32,
+                Array<ImageResolve>,
+            ),
+        >(load("vkCmdResolveImage\0")),
         cmd_clear_color_image: transmute(load("vkCmdClearColorImage\0")),
+        cmd_clear_depth_stencil_image: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkImage>,
+                ImageLayout,
+                &ClearDepthStencilValue,
+                u32,
+                Array<ImageSubresourceRange>,
+            ),
+        >(load(
+            "vkCmdClearDepthStencilImage\0",
+        )),
+        cmd_clear_attachments: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                u32,
+                Array<ClearAttachment>,
+                u32,
+                Array<ClearRect>,
+            ),
+        >(load("vkCmdClearAttachments\0")),
         cmd_pipeline_barrier: transmute(load("vkCmdPipelineBarrier\0")),
+        cmd_set_event: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkEvent>,
+                PipelineStageFlags,
+            ),
+        >(load("vkCmdSetEvent\0")),
+        cmd_reset_event: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkEvent>,
+                PipelineStageFlags,
+            ),
+        >(load("vkCmdResetEvent\0")),
+        cmd_wait_events: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                u32,
+                Array<Ref<VkEvent>>,
+                PipelineStageFlags,
+                PipelineStageFlags,
+                u32,
+                Option<Array<MemoryBarrier>>,
+                u32,
+                Option<Array<VkBufferMemoryBarrier>>,
+                u32,
+                Option<Array<VkImageMemoryBarrier>>,
+            ),
+        >(load("vkCmdWaitEvents\0")),
         cmd_begin_render_pass: transmute(load("vkCmdBeginRenderPass\0")),
         cmd_next_subpass: transmute(load("vkCmdNextSubpass\0")),
         cmd_end_render_pass: transmute(load("vkCmdEndRenderPass\0")),
@@ -667,6 +1182,86 @@ unsafe fn new_device_fn(inst: &Instance, device: Ref<VkDevice>) -> DeviceFn {
         )),
         cmd_dispatch: transmute(load("vkCmdDispatch\0")),
         cmd_dispatch_indirect: transmute(load("vkCmdDispatchIndirect\0")),
+        create_query_pool: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                &QueryPoolCreateInfo,
+                Option<&'_ AllocationCallbacks>,
+                &mut Option<Handle<VkQueryPool>>,
+            ) -> VkResult,
+        >(load("vkCreateQueryPool\0")),
+        destroy_query_pool: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                Mut<VkQueryPool>,
+                Option<&'_ AllocationCallbacks>,
+            ),
+        >(load("vkDestroyQueryPool\0")),
+        get_query_pool_results: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Ref<VkDevice>,
+                Ref<VkQueryPool>,
+                u32,
+                u32,
+                usize,
+                NonNull<c_void>,
+                u64,
+                QueryResultFlags,
+            ) -> VkResult,
+        >(load("vkGetQueryPoolResults\0")),
+        cmd_begin_query: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkQueryPool>,
+                u32,
+                QueryControlFlags,
+            ),
+        >(load("vkCmdBeginQuery\0")),
+        cmd_end_query: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkQueryPool>,
+                u32,
+            ),
+        >(load("vkCmdEndQuery\0")),
+        cmd_reset_query_pool: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkQueryPool>,
+                u32,
+                u32,
+            ),
+        >(load("vkCmdResetQueryPool\0")),
+        cmd_write_timestamp: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                PipelineStageFlags,
+                Ref<VkQueryPool>,
+                u32,
+            ),
+        >(load("vkCmdWriteTimestamp\0")),
+        cmd_copy_query_pool_results: transmute::<
+            NonNull<c_void>,
+            unsafe extern "system" fn(
+                Mut<VkCommandBuffer>,
+                Ref<VkQueryPool>,
+                u32,
+                u32,
+                Ref<VkBuffer>,
+                u64,
+                u64,
+                QueryResultFlags,
+            ),
+        >(load(
+            "vkCmdCopyQueryPoolResults\0",
+        )),
     }
 }
 
@@ -688,6 +1283,16 @@ impl Instance {
         })
     }
 
+    /// Loads device function. Returns None if the function was not found, for
+    /// functions that depend on the API version or an extension.
+    fn try_load(
+        &self, device: Ref<VkDevice>, name: &str,
+    ) -> Option<NonNull<c_void>> {
+        unsafe {
+            (self.fun.get_device_proc_addr)(device, name.try_into().unwrap())
+        }
+    }
+
     /// Loads instance function. Panics if the string is not null-terminated or
     /// the function was not found.
     #[doc = crate::man_link!(vkGetInstanceProcAddr)]
@@ -703,4 +1308,12 @@ impl Device {
     pub fn get_proc_addr(&self, name: &str) -> NonNull<c_void> {
         self.instance().load(self.handle(), name)
     }
+
+    /// Loads device function. Returns None if the function was not found, for
+    /// functions that depend on the API version or an extension.
+    pub(crate) fn try_get_proc_addr(
+        &self, name: &str,
+    ) -> Option<NonNull<c_void>> {
+        self.instance().try_load(self.handle(), name)
+    }
 }
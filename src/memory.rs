@@ -6,9 +6,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::any::Any;
+use std::ffi::c_void;
 use std::marker::PhantomData;
+use std::ops::Range;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
 use std::ptr::NonNull;
+use std::sync::{Mutex, MutexGuard, Weak};
 
+use crate::enums::*;
 use crate::error::{Error, ErrorAndSelf, Result, ResultAndSelf};
 use crate::subobject::{Owner, Subobject};
 use crate::types::*;
@@ -18,8 +25,26 @@ use crate::vk::Device;
 pub struct MemoryLifetime {
     handle: Handle<VkDeviceMemory>,
     device: Arc<Device>,
+    /// Imported host memory, which is dropped after the device memory is
+    /// freed.
+    host: Option<Box<dyn Any + Send + Sync>>,
 }
 
+/// A host allocation which can be imported as device memory with
+/// [`DeviceMemory::import_host`].
+///
+/// # Safety
+/// The slice returned by [`AsMut::as_mut`] must always have the same address
+/// and length, even if the object is moved, and must remain valid until the
+/// object is dropped.
+pub unsafe trait HostAllocation:
+    AsMut<[u8]> + Send + Sync + 'static
+{
+}
+
+unsafe impl HostAllocation for Box<[u8]> {}
+unsafe impl HostAllocation for Vec<u8> {}
+
 /// A piece of
 #[doc = crate::spec_link!("device memory", "11", "memory-device")]
 #[derive(Debug)]
@@ -27,6 +52,45 @@ pub struct DeviceMemory {
     inner: Owner<MemoryLifetime>,
     allocation_size: u64,
     memory_type_index: u32,
+    property_flags: MemoryPropertyFlags,
+    export_handle_types: ExternalMemoryHandleTypeFlags,
+    import_handle_type: ExternalMemoryHandleTypeFlags,
+    bindings: Mutex<Bindings>,
+}
+
+/// The resources bound to a [`DeviceMemory`], for checking buffer-image
+/// granularity.
+#[derive(Debug, Default)]
+pub(crate) struct Bindings(Vec<Binding>);
+
+#[derive(Debug)]
+struct Binding {
+    start: u64,
+    end: u64,
+    linear: bool,
+    resource: Weak<dyn Send + Sync>,
+}
+
+/// The resource a piece of memory is dedicated to.
+#[derive(Debug)]
+pub(crate) enum Dedicated<'a> {
+    Buffer(Ref<'a, VkBuffer>),
+    Image(Ref<'a, VkImage>),
+}
+
+/// Extension structures to chain onto a memory allocation.
+#[derive(Debug, Default)]
+pub(crate) struct AllocateOptions<'a> {
+    pub priority: Option<f32>,
+    /// `allocation_size` must match the resource's requirements if this is
+    /// set.
+    pub dedicated: Option<Dedicated<'a>>,
+    pub export: ExternalMemoryHandleTypeFlags,
+    /// Ownership of the fd is transferred to the implementation only if the
+    /// allocation succeeds.
+    #[cfg(unix)]
+    pub import_fd: Option<(ExternalMemoryHandleTypeFlags, RawFd)>,
+    pub import_host: Option<NonNull<c_void>>,
 }
 
 impl DeviceMemory {
@@ -35,11 +99,255 @@ impl DeviceMemory {
     #[doc = crate::man_link!(vkAllocateMemory)]
     pub fn new(
         device: &Arc<Device>, allocation_size: u64, memory_type_index: u32,
+    ) -> Result<Self> {
+        Self::allocate(
+            device,
+            allocation_size,
+            memory_type_index,
+            Default::default(),
+        )
+    }
+
+    /// Allocate memory with a `priority` between 0 and 1, which the
+    /// implementation may use to decide which allocations to keep in
+    /// device-local memory. Requires the VK_EXT_memory_priority extension and
+    /// the `memory_priority` feature. Returns [`Error::ExtensionNotPresent`] if
+    /// the extension is not enabled, [`Error::MissingFeatures`] if the feature
+    /// is not enabled, [`Error::InvalidArgument`] if `priority` is out of
+    /// range, and [`Error::OutOfBounds`] if no memory type exists with the
+    /// given index.
+    #[doc = crate::man_link!(VkMemoryPriorityAllocateInfoEXT)]
+    pub fn with_priority(
+        device: &Arc<Device>, allocation_size: u64, memory_type_index: u32,
+        priority: f32,
+    ) -> Result<Self> {
+        if !(0.0..=1.0).contains(&priority) {
+            return Err(Error::InvalidArgument);
+        }
+        Self::allocate(
+            device,
+            allocation_size,
+            memory_type_index,
+            AllocateOptions { priority: Some(priority), ..Default::default() },
+        )
+    }
+
+    /// Allocate memory which can be exported as any of `handle_types`.
+    /// Requires Vulkan 1.1 or VK_KHR_external_memory. Returns
+    /// [`Error::OutOfBounds`] if no memory type exists with the given index.
+    #[doc = crate::man_link!(VkExportMemoryAllocateInfo)]
+    pub fn new_exportable(
+        device: &Arc<Device>, allocation_size: u64, memory_type_index: u32,
+        handle_types: ExternalMemoryHandleTypeFlags,
+    ) -> Result<Self> {
+        Self::allocate(
+            device,
+            allocation_size,
+            memory_type_index,
+            AllocateOptions { export: handle_types, ..Default::default() },
+        )
+    }
+
+    /// Import memory from a file descriptor of type `handle_type`, which must
+    /// be a single type. The file descriptor is owned by the implementation if
+    /// the import succeeds, and closed if it fails. The memory can only be
+    /// bound to buffers and images created with `new_external` with
+    /// `handle_type`. Requires VK_KHR_external_memory_fd. Returns
+    /// [`Error::InvalidArgument`] if `handle_type` is not a single type, and
+    /// [`Error::OutOfBounds`] if no memory type exists with the given index.
+    ///
+    /// # Safety
+    /// `allocation_size` and `memory_type_index` must match those of the
+    /// memory `fd` was exported from, for
+    /// [`OPAQUE_FD`](ExternalMemoryHandleTypeFlags::OPAQUE_FD) handles. For
+    /// other handle types, `allocation_size` must not exceed the size of the
+    /// underlying memory and `memory_type_index` must be one of those
+    /// reported for it by the implementation.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(VkImportMemoryFdInfoKHR)]
+    pub unsafe fn import_fd(
+        device: &Arc<Device>, allocation_size: u64, memory_type_index: u32,
+        handle_type: ExternalMemoryHandleTypeFlags, fd: OwnedFd,
+    ) -> Result<Self> {
+        if handle_type.bits().count_ones() != 1 {
+            return Err(Error::InvalidArgument);
+        }
+        let memory = Self::allocate(
+            device,
+            allocation_size,
+            memory_type_index,
+            AllocateOptions {
+                import_fd: Some((handle_type, fd.as_raw_fd())),
+                ..Default::default()
+            },
+        )?;
+        // The implementation owns it now.
+        let _ = fd.into_raw_fd();
+        Ok(memory)
+    }
+
+    /// Import a host allocation as device memory. The allocation is kept alive
+    /// until the memory and everything bound to it are dropped, and must not
+    /// be accessed by the host in the meantime except through
+    /// [`MappedMemory`]. The memory can only be bound to buffers and images
+    /// created with `new_external` with
+    /// [`HOST_ALLOCATION_EXT`](ExternalMemoryHandleTypeFlags::HOST_ALLOCATION_EXT).
+    /// Requires VK_EXT_external_memory_host. Returns
+    /// [`Error::ExtensionNotPresent`] if the extension is not supported or not
+    /// enabled,
+    /// [`Error::InvalidArgument`] if the allocation is empty, its address or
+    /// size is not a multiple of
+    /// [`min_imported_host_pointer_alignment`](PhysicalDeviceExternalMemoryHostPropertiesEXT::min_imported_host_pointer_alignment),
+    /// or the allocation cannot be imported as the given memory type, and
+    /// [`Error::OutOfBounds`] if no memory type exists with the given index.
+    #[doc = crate::man_link!(VkImportMemoryHostPointerInfoEXT)]
+    pub fn import_host<T: HostAllocation>(
+        device: &Arc<Device>, mut host: T, memory_type_index: u32,
+    ) -> ResultAndSelf<Self, T> {
+        let alignment =
+            match device.physical_device().external_memory_host_properties() {
+                Ok(props) if props.min_imported_host_pointer_alignment != 0 => {
+                    props.min_imported_host_pointer_alignment
+                }
+                Ok(_) => {
+                    return Err(ErrorAndSelf(Error::ExtensionNotPresent, host))
+                }
+                Err(err) => return Err(ErrorAndSelf(err, host)),
+            };
+        let slice = host.as_mut();
+        let allocation_size = slice.len() as u64;
+        if allocation_size == 0
+            || allocation_size % alignment != 0
+            || slice.as_ptr() as u64 % alignment != 0
+        {
+            return Err(ErrorAndSelf(Error::InvalidArgument, host));
+        }
+        let host_pointer = NonNull::from(slice).cast();
+        let handle_type = ExternalMemoryHandleTypeFlags::HOST_ALLOCATION_EXT;
+
+        let get_properties = match device
+            .try_get_proc_addr("vkGetMemoryHostPointerPropertiesEXT\0")
+        {
+            Some(f) => unsafe {
+                std::mem::transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkDevice>,
+                        ExternalMemoryHandleTypeFlags,
+                        NonNull<c_void>,
+                        &mut MemoryHostPointerPropertiesEXT,
+                    ) -> VkResult,
+                >(f)
+            },
+            None => return Err(ErrorAndSelf(Error::ExtensionNotPresent, host)),
+        };
+        let mut props = MemoryHostPointerPropertiesEXT::default();
+        if let Err(err) = unsafe {
+            (get_properties)(
+                device.handle(),
+                handle_type,
+                host_pointer,
+                &mut props,
+            )
+        } {
+            return Err(ErrorAndSelf(err.into(), host));
+        }
+        if memory_type_index >= 32
+            || props.memory_type_bits & (1 << memory_type_index) == 0
+        {
+            return Err(ErrorAndSelf(Error::InvalidArgument, host));
+        }
+
+        let mut memory = match Self::allocate(
+            device,
+            allocation_size,
+            memory_type_index,
+            AllocateOptions {
+                import_host: Some(host_pointer),
+                ..Default::default()
+            },
+        ) {
+            Ok(memory) => memory,
+            Err(err) => return Err(ErrorAndSelf(err, host)),
+        };
+        memory.inner.host = Some(Box::new(host));
+        Ok(memory)
+    }
+
+    pub(crate) fn allocate(
+        device: &Arc<Device>, allocation_size: u64, memory_type_index: u32,
+        options: AllocateOptions<'_>,
     ) -> Result<Self> {
         let mem_types = device.physical_device().memory_properties();
         if memory_type_index >= mem_types.memory_types.len() {
             return Err(Error::OutOfBounds);
         }
+        let property_flags = mem_types.memory_types.as_slice()
+            [memory_type_index as usize]
+            .property_flags;
+
+        let mut next: Option<NonNull<c_void>> = None;
+        let priority_info;
+        if let Some(priority) = options.priority {
+            if !device.is_extension_enabled(crate::ext::MEMORY_PRIORITY) {
+                return Err(Error::ExtensionNotPresent);
+            }
+            device
+                .require_feature(|f| &mut f.memory_priority.memory_priority)?;
+            priority_info = MemoryPriorityAllocateInfoEXT {
+                stype: Default::default(),
+                next,
+                priority,
+            };
+            next = Some(NonNull::from(&priority_info).cast());
+        }
+        let dedicated_info;
+        if let Some(dedicated) = options.dedicated {
+            let (image, buffer) = match dedicated {
+                Dedicated::Buffer(buffer) => (None, Some(buffer)),
+                Dedicated::Image(image) => (Some(image), None),
+            };
+            dedicated_info = MemoryDedicatedAllocateInfo {
+                stype: Default::default(),
+                next,
+                image,
+                buffer,
+            };
+            next = Some(NonNull::from(&dedicated_info).cast());
+        }
+        let export_info;
+        if !options.export.is_empty() {
+            export_info = ExportMemoryAllocateInfo {
+                stype: Default::default(),
+                next,
+                handle_types: options.export,
+            };
+            next = Some(NonNull::from(&export_info).cast());
+        }
+        #[cfg(unix)]
+        let import_fd_info;
+        #[cfg(unix)]
+        if let Some((handle_type, fd)) = options.import_fd {
+            import_fd_info = ImportMemoryFdInfoKHR {
+                stype: Default::default(),
+                next,
+                handle_type,
+                fd,
+            };
+            next = Some(NonNull::from(&import_fd_info).cast());
+        }
+        let import_host_info;
+        if let Some(host_pointer) = options.import_host {
+            import_host_info = ImportMemoryHostPointerInfoEXT {
+                stype: Default::default(),
+                next,
+                handle_type: ExternalMemoryHandleTypeFlags::HOST_ALLOCATION_EXT,
+                host_pointer,
+            };
+            next = Some(NonNull::from(&import_host_info).cast());
+        }
+
         device.increment_memory_alloc_count()?;
         let mut handle = None;
         let result = unsafe {
@@ -47,7 +355,7 @@ impl DeviceMemory {
                 device.handle(),
                 &MemoryAllocateInfo {
                     stype: Default::default(),
-                    next: Default::default(),
+                    next,
                     allocation_size,
                     memory_type_index,
                 },
@@ -59,16 +367,76 @@ impl DeviceMemory {
             device.decrement_memory_alloc_count();
             result?;
         }
+        #[cfg(unix)]
+        let import_fd_type =
+            options.import_fd.map(|(handle_type, _)| handle_type);
+        #[cfg(not(unix))]
+        let import_fd_type = None;
+        let import_handle_type = match options.import_host {
+            Some(_) => ExternalMemoryHandleTypeFlags::HOST_ALLOCATION_EXT,
+            None => import_fd_type.unwrap_or_default(),
+        };
         Ok(Self {
             allocation_size,
             memory_type_index,
+            property_flags,
+            export_handle_types: options.export,
+            import_handle_type,
+            bindings: Default::default(),
             inner: Owner::new(MemoryLifetime {
                 handle: handle.unwrap(),
                 device: device.clone(),
+                host: None,
             }),
         })
     }
 
+    /// Export the memory as a file descriptor of type `handle_type`, which
+    /// must be one of the types the memory was
+    /// [created](Self::new_exportable()) with. Each call returns a new file
+    /// descriptor. Requires VK_KHR_external_memory_fd. Returns
+    /// [`Error::InvalidArgument`] if `handle_type` is not a single exportable
+    /// type, and [`Error::ExtensionNotPresent`] if the extension is not
+    /// enabled.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(vkGetMemoryFdKHR)]
+    pub fn export_fd(
+        &self, handle_type: ExternalMemoryHandleTypeFlags,
+    ) -> Result<OwnedFd> {
+        if handle_type.bits().count_ones() != 1
+            || !self.export_handle_types.contains(handle_type)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        let device = self.device();
+        let get_memory_fd: unsafe extern "system" fn(
+            Ref<VkDevice>,
+            &MemoryGetFdInfoKHR,
+            &mut RawFd,
+        ) -> VkResult = unsafe {
+            std::mem::transmute(
+                device
+                    .try_get_proc_addr("vkGetMemoryFdKHR\0")
+                    .ok_or(Error::ExtensionNotPresent)?,
+            )
+        };
+        let mut fd = -1;
+        unsafe {
+            (get_memory_fd)(
+                device.handle(),
+                &MemoryGetFdInfoKHR {
+                    stype: Default::default(),
+                    next: Default::default(),
+                    memory: self.handle(),
+                    handle_type,
+                },
+                &mut fd,
+            )?;
+            Ok(OwnedFd::from_raw_fd(fd))
+        }
+    }
+
     /// Borrows the inner Vulkan handle.
     pub fn handle(&self) -> Ref<VkDeviceMemory> {
         self.inner.handle.borrow()
@@ -81,6 +449,23 @@ impl DeviceMemory {
     pub fn device(&self) -> &Arc<Device> {
         &self.inner.device
     }
+    /// Returns the index of the memory type.
+    pub fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+    /// Returns the properties of the memory type.
+    pub fn property_flags(&self) -> MemoryPropertyFlags {
+        self.property_flags
+    }
+    /// Returns true if a resource created to be bound to external memory of
+    /// `handle_types` can be bound to this memory.
+    pub(crate) fn external_compatible(
+        &self, handle_types: ExternalMemoryHandleTypeFlags,
+    ) -> bool {
+        (self.export_handle_types.is_empty()
+            || self.export_handle_types.intersects(handle_types))
+            && handle_types.contains(self.import_handle_type)
+    }
     /// Extend the lifetime of the memory until the returned object is dropped.
     pub(crate) fn resource(&self) -> Subobject<MemoryLifetime> {
         Subobject::new(&self.inner)
@@ -93,6 +478,40 @@ impl DeviceMemory {
             && !overflow
             && end <= self.allocation_size
     }
+    /// Lock the list of resources bound to the memory.
+    pub(crate) fn bindings(&self) -> MutexGuard<'_, Bindings> {
+        self.bindings.lock().unwrap()
+    }
+}
+
+impl Bindings {
+    /// Returns true if a resource of `size` bytes at `offset` would not share
+    /// a page of `granularity` bytes with a live resource of the other kind.
+    /// Buffers and linear images are linear, optimal images are not.
+    pub fn check(
+        &mut self, offset: u64, size: u64, linear: bool, granularity: u64,
+    ) -> bool {
+        self.0.retain(|b| b.resource.strong_count() != 0);
+        let page = |addr: u64| addr & !(granularity - 1);
+        let (first, last) = (page(offset), page(offset + size - 1));
+        self.0.iter().all(|b| {
+            b.linear == linear
+                || page(b.end - 1) < first
+                || page(b.start) > last
+        })
+    }
+    /// Record that `resource` is bound at `offset`.
+    pub fn push<T: Send + Sync + 'static>(
+        &mut self, offset: u64, size: u64, linear: bool, resource: &Arc<T>,
+    ) {
+        let resource = Arc::downgrade(resource);
+        self.0.push(Binding {
+            start: offset,
+            end: offset + size,
+            linear,
+            resource,
+        })
+    }
 }
 
 impl Drop for MemoryLifetime {
@@ -108,9 +527,15 @@ impl Drop for MemoryLifetime {
     }
 }
 
-/// A [`DeviceMemory`] which has been mapped and can be written to
+/// A [`DeviceMemory`] which has been mapped and can be written to.
+///
+/// If the memory is not `HOST_COHERENT`, device writes must be made visible
+/// with [`invalidate`](Self::invalidate()) before reading them, and host
+/// writes must be made visible with [`flush`](Self::flush()), which is done
+/// automatically when a [`MemoryWrite`] is dropped.
 pub struct MappedMemory {
     memory: DeviceMemory,
+    offset: u64,
     size: usize,
     ptr: NonNull<u8>,
 }
@@ -123,17 +548,23 @@ pub struct MemoryRead<'a> {
     _lt: PhantomData<&'a ()>,
 }
 /// A structure for copying data into mapped memory. Implements
-/// [`std::io::Write`].
+/// [`std::io::Write`]. If the memory is not `HOST_COHERENT`, the written bytes
+/// are flushed when it is dropped.
 pub struct MemoryWrite<'a> {
     ptr: NonNull<u8>,
     end: *const u8,
-    _lt: PhantomData<&'a ()>,
+    start: usize,
+    memory: &'a MappedMemory,
 }
 
 #[allow(clippy::len_without_is_empty)]
 impl DeviceMemory {
     /// Map the memory so it can be written to. Returns [`Error::OutOfBounds`] if
     /// `offset` and `size` are out of bounds.
+    ///
+    /// The mapped range is extended to a multiple of
+    /// [`non_coherent_atom_size`](PhysicalDeviceLimits::non_coherent_atom_size),
+    /// so that any part of it can be flushed and invalidated.
     pub fn map(
         mut self, offset: u64, size: usize,
     ) -> ResultAndSelf<MappedMemory, Self> {
@@ -142,21 +573,30 @@ impl DeviceMemory {
         {
             return Err(ErrorAndSelf(Error::OutOfBounds, self));
         }
+        let atom = self.device().limits().non_coherent_atom_size;
+        let map_offset = align_down(offset, atom);
+        let map_end = align_up(end, atom).min(self.allocation_size);
         let inner = &mut *self.inner;
         let mut ptr = std::ptr::null_mut();
         unsafe {
             if let Err(err) = (inner.device.fun.map_memory)(
                 inner.device.handle(),
                 inner.handle.borrow_mut(),
-                offset,
-                size as u64,
+                map_offset,
+                map_end - map_offset,
                 Default::default(),
                 &mut ptr,
             ) {
                 return Err(ErrorAndSelf(err.into(), self));
             }
         }
-        Ok(MappedMemory { memory: self, size, ptr: NonNull::new(ptr).unwrap() })
+        let ptr = unsafe { ptr.add((offset - map_offset) as usize) };
+        Ok(MappedMemory {
+            memory: self,
+            offset,
+            size,
+            ptr: NonNull::new(ptr).unwrap(),
+        })
     }
     /// Returns the size of the memory in bytes.
     pub fn len(&self) -> u64 {
@@ -211,17 +651,136 @@ impl MappedMemory {
     /// empty.
     #[inline]
     pub fn write_at(&mut self, offset: usize) -> MemoryWrite {
+        let start = offset.min(self.size);
         unsafe {
-            let ptr = self.ptr.as_ptr().add(offset.min(self.size));
+            let ptr = self.ptr.as_ptr().add(start);
             MemoryWrite {
                 ptr: NonNull::new_unchecked(ptr),
                 end: self.ptr.as_ptr().add(self.size),
-                _lt: PhantomData,
+                start,
+                memory: self,
+            }
+        }
+    }
+
+    /// View `len` elements of the memory starting at byte `offset` as a slice
+    /// of `T`. Like [`read_at`](Self::read_at()), the contents may be garbage.
+    /// Returns [`Error::OutOfBounds`] if the range is out of bounds, and
+    /// [`Error::InvalidArgument`] if it is not aligned for `T`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn slice<T: bytemuck::Pod>(
+        &self, offset: usize, len: usize,
+    ) -> Result<&[T]> {
+        let ptr = self.slice_ptr::<T>(offset, len)?;
+        Ok(unsafe { std::slice::from_raw_parts(ptr, len) })
+    }
+
+    /// View `len` elements of the memory starting at byte `offset` as a
+    /// mutable slice of `T`. If the memory is not `HOST_COHERENT`, writes must
+    /// be made visible with [`flush`](Self::flush()). Returns
+    /// [`Error::OutOfBounds`] if the range is out of bounds, and
+    /// [`Error::InvalidArgument`] if it is not aligned for `T`.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn slice_mut<T: bytemuck::Pod>(
+        &mut self, offset: usize, len: usize,
+    ) -> Result<&mut [T]> {
+        let ptr = self.slice_ptr::<T>(offset, len)?;
+        Ok(unsafe { std::slice::from_raw_parts_mut(ptr, len) })
+    }
+
+    #[cfg(feature = "bytemuck")]
+    fn slice_ptr<T>(&self, offset: usize, len: usize) -> Result<*mut T> {
+        let end = len
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|size| size.checked_add(offset));
+        if offset > self.size || !matches!(end, Some(end) if end <= self.size) {
+            return Err(Error::OutOfBounds);
+        }
+        let ptr = unsafe { self.ptr.as_ptr().add(offset) };
+        if ptr as usize % std::mem::align_of::<T>() != 0 {
+            return Err(Error::InvalidArgument);
+        }
+        Ok(ptr as *mut T)
+    }
+
+    /// Make host writes to `range` visible to the device. `range` is relative
+    /// to the start of the mapping. This does nothing if the memory is
+    /// `HOST_COHERENT`. Returns [`Error::OutOfBounds`] if `range` is out of
+    /// bounds.
+    #[doc = crate::man_link!(vkFlushMappedMemoryRanges)]
+    pub fn flush(&self, range: Range<usize>) -> Result<()> {
+        if let Some(range) = self.atom_range(range)? {
+            let device = self.memory.device();
+            unsafe {
+                (device.fun.flush_mapped_memory_ranges)(
+                    device.handle(),
+                    1,
+                    (&[range]).into(),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Make device writes to `range` visible to the host. `range` is relative
+    /// to the start of the mapping. This does nothing if the memory is
+    /// `HOST_COHERENT`. Returns [`Error::OutOfBounds`] if `range` is out of
+    /// bounds.
+    #[doc = crate::man_link!(vkInvalidateMappedMemoryRanges)]
+    pub fn invalidate(&mut self, range: Range<usize>) -> Result<()> {
+        if let Some(range) = self.atom_range(range)? {
+            let device = self.memory.device();
+            unsafe {
+                (device.fun.invalidate_mapped_memory_ranges)(
+                    device.handle(),
+                    1,
+                    (&[range]).into(),
+                )?;
             }
         }
+        Ok(())
+    }
+
+    /// Extend `range` to multiples of `non_coherent_atom_size`, or return None
+    /// if no flush or invalidate is needed.
+    fn atom_range(
+        &self, range: Range<usize>,
+    ) -> Result<Option<MappedMemoryRange<'_>>> {
+        if range.start > range.end || range.end > self.size {
+            return Err(Error::OutOfBounds);
+        }
+        let coherent = self
+            .memory
+            .property_flags
+            .contains(MemoryPropertyFlags::HOST_COHERENT);
+        if coherent || range.is_empty() {
+            return Ok(None);
+        }
+        let atom = self.memory.device().limits().non_coherent_atom_size;
+        // Stays inside the mapping, which is also extended this way.
+        let start = align_down(self.offset + range.start as u64, atom);
+        let end = align_up(self.offset + range.end as u64, atom)
+            .min(self.memory.allocation_size);
+        Ok(Some(MappedMemoryRange {
+            stype: Default::default(),
+            next: Default::default(),
+            memory: self.memory.handle(),
+            offset: start,
+            size: end - start,
+        }))
     }
 }
 
+fn align_down(value: u64, align: u64) -> u64 {
+    value & !(align - 1)
+}
+
+fn align_up(value: u64, align: u64) -> u64 {
+    align_down(value + align - 1, align)
+}
+
 impl<'a> std::io::Read for MemoryRead<'a> {
     #[inline]
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
@@ -278,13 +837,23 @@ impl<'a> std::io::Write for MemoryWrite<'a> {
         }
     }
 
-    /// Does nothing.
+    /// Does nothing. The written bytes are flushed when the writer is
+    /// dropped.
     #[inline]
     fn flush(&mut self) -> std::io::Result<()> {
         Ok(())
     }
 }
 
+impl<'a> Drop for MemoryWrite<'a> {
+    fn drop(&mut self) {
+        let written =
+            unsafe { self.ptr.as_ptr().offset_from(self.memory.ptr.as_ptr()) };
+        // Errors here can only be out of memory.
+        let _ = self.memory.flush(self.start..written as usize);
+    }
+}
+
 // Access to ptr is properly controlled with borrows
 unsafe impl Send for MappedMemory {}
 unsafe impl Sync for MappedMemory {}
@@ -298,3 +867,174 @@ unsafe impl<'a> Send for MemoryWrite<'a> {}
 unsafe impl<'a> Sync for MemoryWrite<'a> {}
 impl<'a> std::panic::UnwindSafe for MemoryWrite<'a> {}
 impl<'a> std::panic::RefUnwindSafe for MemoryWrite<'a> {}
+
+#[cfg(all(test, unix))]
+mod test {
+    use std::ptr::NonNull;
+
+    use crate::vk;
+
+    #[test]
+    fn export_import_fd() -> vk::Result<()> {
+        let (dev, _) =
+            crate::test_device_with_extensions(&[vk::ext::EXTERNAL_MEMORY_FD])?;
+        let opaque = vk::ExternalMemoryHandleTypeFlags::OPAQUE_FD;
+        let buf = vk::BufferWithoutMemory::new_external(
+            &dev,
+            &vk::BufferCreateInfo {
+                size: 256,
+                usage: vk::BufferUsageFlags::TRANSFER_SRC,
+                ..Default::default()
+            },
+            opaque,
+        )?;
+        let req = buf.memory_requirements();
+        let mem_type = req.memory_type_bits.trailing_zeros();
+        let mem =
+            vk::DeviceMemory::new_exportable(&dev, req.size, mem_type, opaque)?;
+        let dma_buf = vk::ExternalMemoryHandleTypeFlags::DMA_BUF_EXT;
+        assert!(mem.export_fd(dma_buf).is_err());
+        let fd = mem.export_fd(opaque)?;
+        let imported = unsafe {
+            vk::DeviceMemory::import_fd(&dev, req.size, mem_type, opaque, fd)?
+        };
+        drop(mem);
+        let plain = vk::BufferWithoutMemory::new(
+            &dev,
+            &vk::BufferCreateInfo {
+                size: 256,
+                usage: vk::BufferUsageFlags::TRANSFER_SRC,
+                ..Default::default()
+            },
+        )?;
+        assert!(plain.bind_memory(&imported, 0).is_err());
+        buf.bind_memory(&imported, 0)?;
+        Ok(())
+    }
+
+    struct PageAligned(NonNull<u8>, std::alloc::Layout);
+    unsafe impl Send for PageAligned {}
+    unsafe impl Sync for PageAligned {}
+    impl AsMut<[u8]> for PageAligned {
+        fn as_mut(&mut self) -> &mut [u8] {
+            unsafe {
+                std::slice::from_raw_parts_mut(self.0.as_ptr(), self.1.size())
+            }
+        }
+    }
+    impl Drop for PageAligned {
+        fn drop(&mut self) {
+            unsafe { std::alloc::dealloc(self.0.as_ptr(), self.1) }
+        }
+    }
+    unsafe impl vk::HostAllocation for PageAligned {}
+
+    #[test]
+    fn import_host() -> vk::Result<()> {
+        let (dev, _) = crate::test_device_with_extensions(&[
+            vk::ext::EXTERNAL_MEMORY_HOST,
+        ])?;
+        let alignment =
+            dev.physical_device()
+                .external_memory_host_properties()?
+                .min_imported_host_pointer_alignment as usize;
+        let unaligned = vec![0u8; alignment + 1];
+        assert!(vk::DeviceMemory::import_host(&dev, unaligned, 0).is_err());
+
+        let layout =
+            std::alloc::Layout::from_size_align(alignment, alignment).unwrap();
+        let ptr = unsafe { std::alloc::alloc_zeroed(layout) };
+        let mut host = PageAligned(NonNull::new(ptr).unwrap(), layout);
+        let types = dev.physical_device().memory_properties().memory_types;
+        for mem_type in 0..types.len() {
+            match vk::DeviceMemory::import_host(&dev, host, mem_type) {
+                Ok(mem) => {
+                    assert_eq!(mem.len(), alignment as u64);
+                    return Ok(());
+                }
+                Err(vk::ErrorAndSelf(_, h)) => host = h,
+            }
+        }
+        panic!("No memory type can import host memory")
+    }
+
+    /// Maps `size` bytes at `offset` of each host-visible memory type.
+    fn map_each(
+        dev: &std::sync::Arc<vk::Device>, offset: u64, size: usize,
+    ) -> vk::Result<Vec<vk::MappedMemory>> {
+        let props = dev.physical_device().memory_properties();
+        let mut result = vec![];
+        for (i, ty) in props.memory_types.iter().enumerate() {
+            if ty.property_flags.contains(vk::MemoryPropertyFlags::HOST_VISIBLE)
+            {
+                let mem = vk::DeviceMemory::new(dev, 1024, i as u32)?;
+                result.push(mem.map(offset, size)?);
+            }
+        }
+        Ok(result)
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn flush() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let atom = dev.limits().non_coherent_atom_size;
+        for mut mapped in map_each(&dev, 1, 100)? {
+            mapped.flush(0..100)?;
+            mapped.flush(5..5)?;
+            mapped.invalidate(10..20)?;
+            assert_eq!(mapped.flush(0..101), Err(vk::Error::OutOfBounds));
+            assert_eq!(mapped.flush(20..10), Err(vk::Error::OutOfBounds));
+            assert_eq!(mapped.invalidate(0..101), Err(vk::Error::OutOfBounds));
+            assert_eq!(mapped.invalidate(20..10), Err(vk::Error::OutOfBounds));
+
+            let coherent = mapped
+                .memory()
+                .property_flags()
+                .contains(vk::MemoryPropertyFlags::HOST_COHERENT);
+            let range = mapped.atom_range(10..20)?;
+            assert_eq!(range.is_none(), coherent);
+            if let Some(range) = range {
+                assert_eq!(range.offset % atom, 0);
+                assert!(range.offset <= 11);
+                assert!(range.offset + range.size >= 21);
+                assert!(range.offset + range.size <= 1024);
+                assert!(
+                    range.size % atom == 0 || range.offset + range.size == 1024
+                );
+            }
+            assert!(mapped.atom_range(5..5)?.is_none());
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn slice() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        for mut mapped in map_each(&dev, 0, 64)? {
+            mapped.slice_mut::<u32>(0, 16)?.fill(7);
+            assert_eq!(mapped.slice::<u32>(4, 15)?, [7; 15]);
+            assert_eq!(mapped.slice::<u32>(64, 0)?, []);
+            assert_eq!(mapped.slice::<u32>(0, 17), Err(vk::Error::OutOfBounds));
+            assert_eq!(mapped.slice::<u32>(65, 0), Err(vk::Error::OutOfBounds));
+            assert_eq!(
+                mapped.slice::<u32>(4, usize::MAX),
+                Err(vk::Error::OutOfBounds)
+            );
+            assert_eq!(
+                mapped.slice::<u32>(2, 1),
+                Err(vk::Error::InvalidArgument)
+            );
+            assert_eq!(
+                mapped.slice_mut::<u32>(1, 16).unwrap_err(),
+                vk::Error::OutOfBounds
+            );
+            assert_eq!(
+                mapped.slice_mut::<u32>(1, 1).unwrap_err(),
+                vk::Error::InvalidArgument
+            );
+        }
+        Ok(())
+    }
+}
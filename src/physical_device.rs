@@ -8,11 +8,430 @@
 
 use std::mem::MaybeUninit;
 
-use crate::error::Result;
-use crate::ffi::ArrayMut;
+use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::ffi::{c_void, ArrayMut, NonNull, Str};
 use crate::instance::Instance;
 use crate::types::*;
 
+pub(crate) const VERSION_1_1: u32 = 1 << 22 | 1 << 12;
+pub(crate) const VERSION_1_2: u32 = 1 << 22 | 2 << 12;
+pub(crate) const VERSION_1_3: u32 = 1 << 22 | 3 << 12;
+
+/// The features of each core Vulkan version and of the device extensions used
+/// by Maia. Features of versions or extensions that the device or instance do
+/// not support are all false.
+#[derive(Debug, Default, Clone)]
+pub struct DeviceFeatures {
+    pub features: PhysicalDeviceFeatures,
+    pub vulkan_1_1: PhysicalDeviceVulkan11Features,
+    pub vulkan_1_2: PhysicalDeviceVulkan12Features,
+    pub vulkan_1_3: PhysicalDeviceVulkan13Features,
+    /// Requires VK_EXT_memory_priority.
+    pub memory_priority: PhysicalDeviceMemoryPriorityFeaturesEXT,
+}
+
+/// The properties of each core Vulkan version. Properties of versions that the
+/// device or instance do not support are `None`.
+#[derive(Debug)]
+pub struct DeviceProperties {
+    pub properties: PhysicalDeviceProperties,
+    pub vulkan_1_1: Option<PhysicalDeviceVulkan11Properties>,
+    pub vulkan_1_2: Option<PhysicalDeviceVulkan12Properties>,
+    pub vulkan_1_3: Option<PhysicalDeviceVulkan13Properties>,
+}
+
+impl DeviceFeatures {
+    /// Returns the features which are enabled in `self` but not in
+    /// `supported`.
+    pub fn missing_features(&self, supported: &Self) -> FeatureNames {
+        FeatureNames([
+            self.features.missing_features(&supported.features),
+            self.vulkan_1_1.missing_features(&supported.vulkan_1_1),
+            self.vulkan_1_2.missing_features(&supported.vulkan_1_2),
+            self.vulkan_1_3.missing_features(&supported.vulkan_1_3),
+            self.memory_priority.missing_features(&supported.memory_priority),
+        ])
+    }
+}
+
+/// A set of features of a [`DeviceFeatures`], such as those reported by
+/// [`Error::MissingFeatures`].
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+pub struct FeatureNames([u64; 5]);
+
+impl FeatureNames {
+    /// Returns true if the set contains no features.
+    pub fn is_empty(&self) -> bool {
+        self.0 == [0; 5]
+    }
+    /// Returns the names of the features in the set, which are the same as
+    /// the corresponding field names.
+    pub fn iter(&self) -> impl Iterator<Item = &'static str> + '_ {
+        let names = [
+            PhysicalDeviceFeatures::NAMES,
+            PhysicalDeviceVulkan11Features::NAMES,
+            PhysicalDeviceVulkan12Features::NAMES,
+            PhysicalDeviceVulkan13Features::NAMES,
+            PhysicalDeviceMemoryPriorityFeaturesEXT::NAMES,
+        ];
+        self.0.iter().zip(names).flat_map(|(&bits, names)| {
+            names
+                .iter()
+                .enumerate()
+                .filter(move |&(i, _)| bits & 1 << i != 0)
+                .map(|(_, &name)| name)
+        })
+    }
+}
+
+impl std::fmt::Debug for FeatureNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Implements `missing_features` for a features structure.
+macro_rules! missing_feature {
+    ($name: ident, $($field: ident),* $(,)?) => {
+        impl $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+            /// Returns a bit for each feature which is enabled in `self` but
+            /// not in `supported`, in the order of [`Self::NAMES`].
+            fn missing_features(&self, supported: &Self) -> u64 {
+                [$(self.$field.as_bool() && !supported.$field.as_bool()),*]
+                    .iter()
+                    .enumerate()
+                    .fold(0, |bits, (i, &missing)| bits | (missing as u64) << i)
+            }
+        }
+    };
+}
+missing_feature!(
+    PhysicalDeviceFeatures,
+    robust_buffer_access,
+    full_draw_index_uint32,
+    image_cube_array,
+    independent_blend,
+    geometry_shader,
+    tessellation_shader,
+    sample_rate_shading,
+    dual_src_blend,
+    logic_op,
+    multi_draw_indirect,
+    draw_indirect_first_instance,
+    depth_clamp,
+    depth_bias_clamp,
+    fill_mode_non_solid,
+    depth_bounds,
+    wide_lines,
+    large_points,
+    alpha_to_one,
+    multi_viewport,
+    sampler_anisotropy,
+    texture_compression_etc2,
+    texture_compression_astc_ldr,
+    texture_compression_bc,
+    occlusion_query_precise,
+    pipeline_statistics_query,
+    vertex_pipeline_stores_and_atomics,
+    fragment_stores_and_atomics,
+    shader_tessellation_and_geometry_point_size,
+    shader_image_gather_extended,
+    shader_storage_image_extended_formats,
+    shader_storage_image_multisample,
+    shader_storage_image_read_without_format,
+    shader_storage_image_write_without_format,
+    shader_uniform_buffer_array_dynamic_indexing,
+    shader_sampled_image_array_dynamic_indexing,
+    shader_storage_buffer_array_dynamic_indexing,
+    shader_storage_image_array_dynamic_indexing,
+    shader_clip_distance,
+    shader_cull_distance,
+    shader_float64,
+    shader_int64,
+    shader_int16,
+    shader_resource_residency,
+    shader_resource_min_lod,
+    sparse_binding,
+    sparse_residency_buffer,
+    sparse_residency_image_2d,
+    sparse_residency_image_3d,
+    sparse_residency2_samples,
+    sparse_residency4_samples,
+    sparse_residency8_samples,
+    sparse_residency16_samples,
+    sparse_residency_aliased,
+    variable_multisample_rate,
+    inherited_queries,
+);
+missing_feature!(
+    PhysicalDeviceVulkan11Features,
+    storage_buffer16_bit_access,
+    uniform_and_storage_buffer16_bit_access,
+    storage_push_constant16,
+    storage_input_output16,
+    multiview,
+    multiview_geometry_shader,
+    multiview_tessellation_shader,
+    variable_pointers_storage_buffer,
+    variable_pointers,
+    protected_memory,
+    sampler_ycbcr_conversion,
+    shader_draw_parameters,
+);
+missing_feature!(
+    PhysicalDeviceVulkan12Features,
+    sampler_mirror_clamp_to_edge,
+    draw_indirect_count,
+    storage_buffer8_bit_access,
+    uniform_and_storage_buffer8_bit_access,
+    storage_push_constant8,
+    shader_buffer_int64_atomics,
+    shader_shared_int64_atomics,
+    shader_float16,
+    shader_int8,
+    descriptor_indexing,
+    shader_input_attachment_array_dynamic_indexing,
+    shader_uniform_texel_buffer_array_dynamic_indexing,
+    shader_storage_texel_buffer_array_dynamic_indexing,
+    shader_uniform_buffer_array_non_uniform_indexing,
+    shader_sampled_image_array_non_uniform_indexing,
+    shader_storage_buffer_array_non_uniform_indexing,
+    shader_storage_image_array_non_uniform_indexing,
+    shader_input_attachment_array_non_uniform_indexing,
+    shader_uniform_texel_buffer_array_non_uniform_indexing,
+    shader_storage_texel_buffer_array_non_uniform_indexing,
+    descriptor_binding_uniform_buffer_update_after_bind,
+    descriptor_binding_sampled_image_update_after_bind,
+    descriptor_binding_storage_image_update_after_bind,
+    descriptor_binding_storage_buffer_update_after_bind,
+    descriptor_binding_uniform_texel_buffer_update_after_bind,
+    descriptor_binding_storage_texel_buffer_update_after_bind,
+    descriptor_binding_update_unused_while_pending,
+    descriptor_binding_partially_bound,
+    descriptor_binding_variable_descriptor_count,
+    runtime_descriptor_array,
+    sampler_filter_minmax,
+    scalar_block_layout,
+    imageless_framebuffer,
+    uniform_buffer_standard_layout,
+    shader_subgroup_extended_types,
+    separate_depth_stencil_layouts,
+    host_query_reset,
+    timeline_semaphore,
+    buffer_device_address,
+    buffer_device_address_capture_replay,
+    buffer_device_address_multi_device,
+    vulkan_memory_model,
+    vulkan_memory_model_device_scope,
+    vulkan_memory_model_availability_visibility_chains,
+    shader_output_viewport_index,
+    shader_output_layer,
+    subgroup_broadcast_dynamic_id,
+);
+missing_feature!(
+    PhysicalDeviceVulkan13Features,
+    robust_image_access,
+    inline_uniform_block,
+    descriptor_binding_inline_uniform_block_update_after_bind,
+    pipeline_creation_cache_control,
+    private_data,
+    shader_demote_to_helper_invocation,
+    shader_terminate_invocation,
+    subgroup_size_control,
+    compute_full_subgroups,
+    synchronization2,
+    texture_compression_astc_hdr,
+    shader_zero_initialize_workgroup_memory,
+    dynamic_rendering,
+    shader_integer_dot_product,
+    maintenance4,
+);
+missing_feature!(PhysicalDeviceMemoryPriorityFeaturesEXT, memory_priority);
+
+type Chain = Option<NonNull<c_void>>;
+
+/// The extension structures of [`DeviceFeatures`], linked together. Must not be
+/// moved while linked.
+pub(crate) struct FeaturesChain {
+    vulkan_1_1: PhysicalDeviceVulkan11Features<Chain>,
+    vulkan_1_2: PhysicalDeviceVulkan12Features<Chain>,
+    vulkan_1_3: PhysicalDeviceVulkan13Features<Chain>,
+    // Vulkan 1.1 has no PhysicalDeviceVulkan11Features.
+    storage_16bit: PhysicalDevice16BitStorageFeatures<Chain>,
+    multiview: PhysicalDeviceMultiviewFeatures<Chain>,
+    variable_pointers: PhysicalDeviceVariablePointersFeatures<Chain>,
+    protected_memory: PhysicalDeviceProtectedMemoryFeatures<Chain>,
+    sampler_ycbcr: PhysicalDeviceSamplerYcbcrConversionFeatures<Chain>,
+    draw_parameters: PhysicalDeviceShaderDrawParametersFeatures<Chain>,
+    memory_priority: PhysicalDeviceMemoryPriorityFeaturesEXT<Chain>,
+}
+
+impl FeaturesChain {
+    pub fn new(features: &DeviceFeatures) -> Self {
+        let v11 = &features.vulkan_1_1;
+        // Safety: Null has the same representation as a null Chain.
+        unsafe {
+            Self {
+                vulkan_1_1: std::mem::transmute::<
+                    PhysicalDeviceVulkan11Features,
+                    PhysicalDeviceVulkan11Features<Chain>,
+                >(v11.clone()),
+                vulkan_1_2: std::mem::transmute::<
+                    PhysicalDeviceVulkan12Features,
+                    PhysicalDeviceVulkan12Features<Chain>,
+                >(features.vulkan_1_2.clone()),
+                vulkan_1_3: std::mem::transmute::<
+                    PhysicalDeviceVulkan13Features,
+                    PhysicalDeviceVulkan13Features<Chain>,
+                >(features.vulkan_1_3.clone()),
+                storage_16bit: PhysicalDevice16BitStorageFeatures {
+                    storage_buffer16_bit_access: v11
+                        .storage_buffer16_bit_access,
+                    uniform_and_storage_buffer16_bit_access: v11
+                        .uniform_and_storage_buffer16_bit_access,
+                    storage_push_constant16: v11.storage_push_constant16,
+                    storage_input_output16: v11.storage_input_output16,
+                    ..Default::default()
+                },
+                multiview: PhysicalDeviceMultiviewFeatures {
+                    multiview: v11.multiview,
+                    multiview_geometry_shader: v11.multiview_geometry_shader,
+                    multiview_tessellation_shader: v11
+                        .multiview_tessellation_shader,
+                    ..Default::default()
+                },
+                variable_pointers: PhysicalDeviceVariablePointersFeatures {
+                    variable_pointers_storage_buffer: v11
+                        .variable_pointers_storage_buffer,
+                    variable_pointers: v11.variable_pointers,
+                    ..Default::default()
+                },
+                protected_memory: PhysicalDeviceProtectedMemoryFeatures {
+                    protected_memory: v11.protected_memory,
+                    ..Default::default()
+                },
+                sampler_ycbcr: PhysicalDeviceSamplerYcbcrConversionFeatures {
+                    sampler_ycbcr_conversion: v11.sampler_ycbcr_conversion,
+                    ..Default::default()
+                },
+                draw_parameters: PhysicalDeviceShaderDrawParametersFeatures {
+                    shader_draw_parameters: v11.shader_draw_parameters,
+                    ..Default::default()
+                },
+                memory_priority: PhysicalDeviceMemoryPriorityFeaturesEXT {
+                    memory_priority: features.memory_priority.memory_priority,
+                    ..Default::default()
+                },
+            }
+        }
+    }
+
+    /// Link the structures supported by `api_version` together in front of
+    /// `next`, and return the head of the chain. The structure of
+    /// VK_EXT_memory_priority is linked if `memory_priority` is true.
+    pub fn link(
+        &mut self, api_version: u32, memory_priority: bool, mut next: Chain,
+    ) -> Chain {
+        if memory_priority {
+            self.memory_priority.next = next;
+            next = Some(NonNull::from(&mut self.memory_priority).cast());
+        }
+        if api_version >= VERSION_1_3 {
+            self.vulkan_1_3.next = next;
+            next = Some(NonNull::from(&mut self.vulkan_1_3).cast());
+        }
+        if api_version >= VERSION_1_2 {
+            self.vulkan_1_2.next = next;
+            next = Some(NonNull::from(&mut self.vulkan_1_2).cast());
+            self.vulkan_1_1.next = next;
+            next = Some(NonNull::from(&mut self.vulkan_1_1).cast());
+        } else if api_version >= VERSION_1_1 {
+            self.storage_16bit.next = next;
+            next = Some(NonNull::from(&mut self.storage_16bit).cast());
+            self.multiview.next = next;
+            next = Some(NonNull::from(&mut self.multiview).cast());
+            self.variable_pointers.next = next;
+            next = Some(NonNull::from(&mut self.variable_pointers).cast());
+            self.protected_memory.next = next;
+            next = Some(NonNull::from(&mut self.protected_memory).cast());
+            self.sampler_ycbcr.next = next;
+            next = Some(NonNull::from(&mut self.sampler_ycbcr).cast());
+            self.draw_parameters.next = next;
+            next = Some(NonNull::from(&mut self.draw_parameters).cast());
+        }
+        next
+    }
+
+    /// Returns the features of the structures supported by `api_version`.
+    pub fn features(
+        self, features: PhysicalDeviceFeatures, api_version: u32,
+    ) -> DeviceFeatures {
+        let mut result = DeviceFeatures {
+            features,
+            memory_priority: PhysicalDeviceMemoryPriorityFeaturesEXT {
+                memory_priority: self.memory_priority.memory_priority,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let Self { mut vulkan_1_1, mut vulkan_1_2, mut vulkan_1_3, .. } = self;
+        vulkan_1_1.next = None;
+        vulkan_1_2.next = None;
+        vulkan_1_3.next = None;
+        // Safety: Null has the same representation as a null Chain.
+        unsafe {
+            if api_version >= VERSION_1_2 {
+                result.vulkan_1_1 = std::mem::transmute::<
+                    PhysicalDeviceVulkan11Features<Chain>,
+                    PhysicalDeviceVulkan11Features,
+                >(vulkan_1_1);
+                result.vulkan_1_2 = std::mem::transmute::<
+                    PhysicalDeviceVulkan12Features<Chain>,
+                    PhysicalDeviceVulkan12Features,
+                >(vulkan_1_2);
+            }
+            if api_version >= VERSION_1_3 {
+                result.vulkan_1_3 = std::mem::transmute::<
+                    PhysicalDeviceVulkan13Features<Chain>,
+                    PhysicalDeviceVulkan13Features,
+                >(vulkan_1_3);
+            }
+        }
+        if (VERSION_1_1..VERSION_1_2).contains(&api_version) {
+            let s = &self.storage_16bit;
+            result.vulkan_1_1 = PhysicalDeviceVulkan11Features {
+                storage_buffer16_bit_access: s.storage_buffer16_bit_access,
+                uniform_and_storage_buffer16_bit_access: s
+                    .uniform_and_storage_buffer16_bit_access,
+                storage_push_constant16: s.storage_push_constant16,
+                storage_input_output16: s.storage_input_output16,
+                multiview: self.multiview.multiview,
+                multiview_geometry_shader: self
+                    .multiview
+                    .multiview_geometry_shader,
+                multiview_tessellation_shader: self
+                    .multiview
+                    .multiview_tessellation_shader,
+                variable_pointers_storage_buffer: self
+                    .variable_pointers
+                    .variable_pointers_storage_buffer,
+                variable_pointers: self.variable_pointers.variable_pointers,
+                protected_memory: self.protected_memory.protected_memory,
+                sampler_ycbcr_conversion: self
+                    .sampler_ycbcr
+                    .sampler_ycbcr_conversion,
+                shader_draw_parameters: self
+                    .draw_parameters
+                    .shader_draw_parameters,
+                ..Default::default()
+            };
+        }
+        result
+    }
+}
+
 /// A physical device. It is not freed separately from the instance and so can
 /// be freely cloned.
 ///
@@ -87,6 +506,183 @@ impl PhysicalDevice {
         }
     }
 
+    #[doc = crate::man_link!(vkGetPhysicalDeviceFeatures)]
+    pub fn features(&self) -> PhysicalDeviceFeatures {
+        let mut result = Default::default();
+        unsafe {
+            (self.instance.fun.get_physical_device_features)(
+                self.handle(),
+                &mut result,
+            );
+        }
+        result
+    }
+
+    #[doc = crate::man_link!(vkGetPhysicalDeviceFormatProperties)]
+    pub fn format_properties(&self, format: Format) -> FormatProperties {
+        let mut result = Default::default();
+        unsafe {
+            (self.instance.fun.get_physical_device_format_properties)(
+                self.handle(),
+                format,
+                &mut result,
+            );
+        }
+        result
+    }
+
+    /// Returns the limits of images created with the given parameters.
+    /// Returns [`Error::FormatNotSupported`] if the combination of parameters
+    /// is not supported.
+    #[doc = crate::man_link!(vkGetPhysicalDeviceImageFormatProperties)]
+    pub fn image_format_properties(
+        &self, format: Format, image_type: ImageType, tiling: ImageTiling,
+        usage: ImageUsageFlags, flags: ImageCreateFlags,
+    ) -> Result<ImageFormatProperties> {
+        let mut result = Default::default();
+        unsafe {
+            (self.instance.fun.get_physical_device_image_format_properties)(
+                self.handle(),
+                format,
+                image_type,
+                tiling,
+                usage,
+                flags,
+                &mut result,
+            )?;
+        }
+        Ok(result)
+    }
+
+    /// Returns the lower of the device's and the instance's API versions, which
+    /// determines the Vulkan version usable with the device.
+    pub fn api_version(&self) -> u32 {
+        self.properties().api_version.min(self.instance.api_version())
+    }
+
+    /// Returns the supported features of each core Vulkan version and of the
+    /// supported extensions in [`DeviceFeatures`]. Requires an instance
+    /// created with Vulkan 1.1 or with VK_KHR_get_physical_device_properties2
+    /// enabled, and returns [`Error::ExtensionNotPresent`] otherwise.
+    #[doc = crate::man_link!(vkGetPhysicalDeviceFeatures2)]
+    pub fn features2(&self) -> Result<DeviceFeatures> {
+        let get_features2 = self
+            .instance
+            .fun
+            .get_physical_device_features2
+            .filter(|_| self.instance.has_properties2())
+            .ok_or(Error::ExtensionNotPresent)?;
+        let api_version = self.api_version();
+        let memory_priority = self
+            .device_extension_properties()?
+            .iter()
+            .any(|e| e.extension_name == crate::ext::MEMORY_PRIORITY);
+        let mut chain = FeaturesChain::new(&Default::default());
+        let mut features2 = PhysicalDeviceFeatures2 {
+            stype: Default::default(),
+            next: chain.link(api_version, memory_priority, None),
+            features: Default::default(),
+        };
+        unsafe { (get_features2)(self.handle(), &mut features2) };
+        Ok(chain.features(features2.features, api_version))
+    }
+
+    /// Returns the properties of each core Vulkan version. Has the same
+    /// requirements as [`features2`](Self::features2()).
+    #[doc = crate::man_link!(vkGetPhysicalDeviceProperties2)]
+    pub fn properties2(&self) -> Result<DeviceProperties> {
+        let api_version = self.api_version();
+        if api_version < VERSION_1_2 {
+            return Ok(DeviceProperties {
+                properties: self.properties2_chain(None)?,
+                vulkan_1_1: None,
+                vulkan_1_2: None,
+                vulkan_1_3: None,
+            });
+        }
+        let mut vulkan_1_1 =
+            MaybeUninit::<PhysicalDeviceVulkan11Properties<Chain>>::uninit();
+        let mut vulkan_1_2 =
+            MaybeUninit::<PhysicalDeviceVulkan12Properties<Chain>>::uninit();
+        let mut vulkan_1_3 =
+            MaybeUninit::<PhysicalDeviceVulkan13Properties<Chain>>::uninit();
+        use std::ptr::addr_of_mut;
+        unsafe {
+            let ptr = vulkan_1_3.as_mut_ptr();
+            addr_of_mut!((*ptr).stype).write(Default::default());
+            addr_of_mut!((*ptr).next).write(None);
+            let ptr = vulkan_1_2.as_mut_ptr();
+            addr_of_mut!((*ptr).stype).write(Default::default());
+            addr_of_mut!((*ptr).next).write(
+                (api_version >= VERSION_1_3)
+                    .then(|| NonNull::from(&mut vulkan_1_3).cast()),
+            );
+            let ptr = vulkan_1_1.as_mut_ptr();
+            addr_of_mut!((*ptr).stype).write(Default::default());
+            addr_of_mut!((*ptr).next)
+                .write(Some(NonNull::from(&mut vulkan_1_2).cast()));
+        }
+        let properties = self
+            .properties2_chain(Some(NonNull::from(&mut vulkan_1_1).cast()))?;
+        // Safety: Null has the same representation as a null Chain.
+        unsafe {
+            addr_of_mut!((*vulkan_1_1.as_mut_ptr()).next).write(None);
+            addr_of_mut!((*vulkan_1_2.as_mut_ptr()).next).write(None);
+            Ok(DeviceProperties {
+                properties,
+                vulkan_1_1: Some(std::mem::transmute::<
+                    PhysicalDeviceVulkan11Properties<Chain>,
+                    PhysicalDeviceVulkan11Properties,
+                >(vulkan_1_1.assume_init())),
+                vulkan_1_2: Some(std::mem::transmute::<
+                    PhysicalDeviceVulkan12Properties<Chain>,
+                    PhysicalDeviceVulkan12Properties,
+                >(vulkan_1_2.assume_init())),
+                vulkan_1_3: (api_version >= VERSION_1_3).then(|| {
+                    std::mem::transmute::<
+                        PhysicalDeviceVulkan13Properties<Chain>,
+                        PhysicalDeviceVulkan13Properties,
+                    >(vulkan_1_3.assume_init())
+                }),
+            })
+        }
+    }
+
+    /// Returns the properties of VK_EXT_external_memory_host. Has the same
+    /// requirements as [`features2`](Self::features2()). If the device does
+    /// not support the extension, the alignment is zero.
+    #[doc = crate::man_link!(VkPhysicalDeviceExternalMemoryHostPropertiesEXT)]
+    pub fn external_memory_host_properties(
+        &self,
+    ) -> Result<PhysicalDeviceExternalMemoryHostPropertiesEXT> {
+        let mut host = PhysicalDeviceExternalMemoryHostPropertiesEXT::default();
+        self.properties2_chain(Some(NonNull::from(&mut host).cast()))?;
+        Ok(host)
+    }
+
+    /// Calls vkGetPhysicalDeviceProperties2 with the chain of structures
+    /// starting at `next`, and returns the core properties.
+    #[doc = crate::man_link!(vkGetPhysicalDeviceProperties2)]
+    fn properties2_chain(
+        &self, next: Chain,
+    ) -> Result<PhysicalDeviceProperties> {
+        let get_properties2 = self
+            .instance
+            .fun
+            .get_physical_device_properties2
+            .filter(|_| self.instance.has_properties2())
+            .ok_or(Error::ExtensionNotPresent)?;
+        let mut result =
+            MaybeUninit::<PhysicalDeviceProperties2<Chain>>::uninit();
+        unsafe {
+            let ptr = result.as_mut_ptr();
+            std::ptr::addr_of_mut!((*ptr).stype).write(Default::default());
+            std::ptr::addr_of_mut!((*ptr).next).write(next);
+            (get_properties2)(self.handle(), &mut result);
+            Ok(result.assume_init().properties)
+        }
+    }
+
     #[doc = crate::man_link!(vkGetPhysicalDeviceQueueFamilyProperties)]
     pub fn queue_family_properties(&self) -> Vec<QueueFamilyProperties> {
         let mut len = 0;
@@ -120,23 +716,85 @@ impl PhysicalDevice {
         result
     }
 
+    /// Returns the estimated amount of memory the process can allocate from
+    /// each heap, in bytes. Requires VK_EXT_memory_budget and the same instance
+    /// support as [`features2`](Self::features2()). Returns
+    /// [`Error::ExtensionNotPresent`] if either is missing.
+    #[doc = crate::man_link!(VkPhysicalDeviceMemoryBudgetPropertiesEXT)]
+    pub fn heap_budget(&self) -> Result<Vec<u64>> {
+        let (count, budget) = self.memory_budget()?;
+        Ok(budget.heap_budget[0..count].to_vec())
+    }
+
+    /// Returns the estimated amount of memory the process is using from each
+    /// heap, in bytes. Has the same requirements as
+    /// [`heap_budget`](Self::heap_budget()).
+    #[doc = crate::man_link!(VkPhysicalDeviceMemoryBudgetPropertiesEXT)]
+    pub fn heap_usage(&self) -> Result<Vec<u64>> {
+        let (count, budget) = self.memory_budget()?;
+        Ok(budget.heap_usage[0..count].to_vec())
+    }
+
+    /// Returns the number of heaps and the budget properties.
+    #[doc = crate::man_link!(vkGetPhysicalDeviceMemoryProperties2)]
+    fn memory_budget(
+        &self,
+    ) -> Result<(usize, PhysicalDeviceMemoryBudgetPropertiesEXT)> {
+        let get_memory_properties2 = self
+            .instance
+            .fun
+            .get_physical_device_memory_properties2
+            .filter(|_| self.instance.has_properties2())
+            .ok_or(Error::ExtensionNotPresent)?;
+        if !self
+            .device_extension_properties()?
+            .iter()
+            .any(|ext| ext.extension_name.as_str() == "VK_EXT_memory_budget")
+        {
+            return Err(Error::ExtensionNotPresent);
+        }
+        let mut budget = PhysicalDeviceMemoryBudgetPropertiesEXT::default();
+        let mut props = PhysicalDeviceMemoryProperties2 {
+            next: Some(&mut budget),
+            ..Default::default()
+        };
+        unsafe { (get_memory_properties2)(self.handle(), &mut props) };
+        let count = props.memory_properties.memory_heaps.len() as usize;
+        Ok((count, budget))
+    }
+
     #[doc = crate::man_link!(vkEnumerateDeviceExtensionProperties)]
     pub fn device_extension_properties(
         &self,
+    ) -> Result<Vec<ExtensionProperties>> {
+        self.extension_properties(None)
+    }
+
+    /// Returns the device extensions provided by the instance layer
+    /// `layer_name`.
+    #[doc = crate::man_link!(vkEnumerateDeviceExtensionProperties)]
+    pub fn device_layer_extension_properties(
+        &self, layer_name: Str<'_>,
+    ) -> Result<Vec<ExtensionProperties>> {
+        self.extension_properties(Some(layer_name))
+    }
+
+    fn extension_properties(
+        &self, layer_name: Option<Str<'_>>,
     ) -> Result<Vec<ExtensionProperties>> {
         let mut len = 0;
         let mut result = Vec::new();
         unsafe {
             (self.instance.fun.enumerate_device_extension_properties)(
                 self.handle(),
-                None,
+                layer_name,
                 &mut len,
                 None,
             )?;
             result.reserve(len as usize);
             (self.instance.fun.enumerate_device_extension_properties)(
                 self.handle(),
-                None,
+                layer_name,
                 &mut len,
                 ArrayMut::from_slice(result.spare_capacity_mut()),
             )?;
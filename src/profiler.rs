@@ -0,0 +1,354 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::io::Write;
+use std::time::Duration;
+
+use crate::command_buffer::{CommandRecording, RenderPassRecording};
+use crate::device::Device;
+use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::query_pool::QueryPool;
+use crate::types::*;
+
+/// A GPU profiler which measures named scopes with timestamp queries.
+///
+/// Each frame, call [`reset`](Profiler::reset()) before recording any scopes,
+/// open and close scopes with [`CommandRecording::begin_scope`] and
+/// [`CommandRecording::end_scope`], and once the fence for the submission has
+/// been waited on, call [`resolve`](Profiler::resolve()) to read back the
+/// timings. Use one profiler per frame in flight.
+#[derive(Debug)]
+pub struct Profiler {
+    pool: Arc<QueryPool>,
+    scopes: Vec<Scope>,
+    open: Vec<usize>,
+    /// Whether a reset of the queries has been recorded.
+    reset: bool,
+    timestamp_period: f64,
+    timestamp_mask: u64,
+}
+
+#[derive(Debug)]
+struct Scope {
+    name: String,
+    depth: u32,
+    begin: u32,
+    end: u32,
+}
+
+/// The measured duration of one profiler scope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScopeTiming {
+    /// The name the scope was opened with.
+    pub name: String,
+    /// The number of scopes enclosing this one.
+    pub depth: u32,
+    /// The time the scope began, relative to the first scope in the frame.
+    pub start: Duration,
+    /// The time between the beginning and end of the scope.
+    pub duration: Duration,
+}
+
+/// Scope timings for one frame, returned by [`Profiler::resolve`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameTimings {
+    scopes: Vec<ScopeTiming>,
+}
+
+impl Profiler {
+    /// Create a profiler which can record up to `max_scopes` scopes per frame
+    /// in command buffers submitted to `queue_family_index`. Returns
+    /// [`Error::OutOfBounds`] if `queue_family_index` is out of bounds, and
+    /// [`Error::InvalidArgument`] if the queue family does not support
+    /// timestamps.
+    pub fn new(
+        device: &Arc<Device>, queue_family_index: u32, max_scopes: u32,
+    ) -> Result<Self> {
+        let families = device.physical_device().queue_family_properties();
+        let family = families
+            .get(queue_family_index as usize)
+            .ok_or(Error::OutOfBounds)?;
+        let valid_bits = family.timestamp_valid_bits;
+        if valid_bits == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let pool = QueryPool::new(
+            device,
+            &QueryPoolCreateInfo {
+                query_type: QueryType::TIMESTAMP,
+                query_count: max_scopes
+                    .checked_mul(2)
+                    .ok_or(Error::InvalidArgument)?,
+                ..Default::default()
+            },
+        )?;
+        Ok(Self {
+            pool,
+            scopes: vec![],
+            open: vec![],
+            reset: false,
+            timestamp_period: device.limits().timestamp_period as f64,
+            timestamp_mask: u64::MAX >> (64 - valid_bits.min(64)),
+        })
+    }
+
+    /// Returns the query pool used to store timestamps.
+    pub fn query_pool(&self) -> &Arc<QueryPool> {
+        &self.pool
+    }
+
+    /// Discard the scopes from the previous frame and record a reset of the
+    /// profiler's queries into `rec`. This must be recorded before any scopes
+    /// in the frame.
+    pub fn reset(&mut self, rec: &mut CommandRecording) -> Result<()> {
+        rec.reset_query_pool(&self.pool, 0, self.pool.len())?;
+        self.scopes.clear();
+        self.open.clear();
+        self.reset = true;
+        Ok(())
+    }
+
+    fn begin(&mut self, name: &str) -> Result<u32> {
+        if !self.reset {
+            return Err(Error::InvalidState);
+        }
+        let begin = self.scopes.len() as u32 * 2;
+        if begin >= self.pool.len() {
+            return Err(Error::OutOfBounds);
+        }
+        self.open.push(self.scopes.len());
+        self.scopes.push(Scope {
+            name: name.to_owned(),
+            depth: self.open.len() as u32 - 1,
+            begin,
+            end: begin + 1,
+        });
+        Ok(begin)
+    }
+
+    fn end(&mut self) -> Result<u32> {
+        let index = self.open.pop().ok_or(Error::InvalidState)?;
+        Ok(self.scopes[index].end)
+    }
+
+    /// Read back the timings of the scopes recorded since the last call to
+    /// [`reset`](Profiler::reset()). This should be called after the fence for
+    /// the submission is waited on. Returns [`Error::NotReady`] if the
+    /// timestamps have not been written yet, and [`Error::InvalidState`] if a
+    /// scope was not ended.
+    #[doc = crate::man_link!(vkGetQueryPoolResults)]
+    pub fn resolve(&self) -> Result<FrameTimings> {
+        if !self.open.is_empty() {
+            return Err(Error::InvalidState);
+        }
+        if self.scopes.is_empty() {
+            return Ok(Default::default());
+        }
+        let mut ticks = vec![0u64; self.scopes.len() * 2];
+        self.pool.get_results_u64(0, &mut ticks, Default::default())?;
+        let to_duration = |ticks: u64| {
+            let ticks = ticks & self.timestamp_mask;
+            Duration::from_secs_f64(ticks as f64 * self.timestamp_period * 1e-9)
+        };
+        let origin = self.scopes.iter().map(|s| ticks[s.begin as usize]).min();
+        let origin = origin.unwrap_or(0);
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|s| {
+                let begin = ticks[s.begin as usize];
+                let end = ticks[s.end as usize];
+                ScopeTiming {
+                    name: s.name.clone(),
+                    depth: s.depth,
+                    start: to_duration(begin.wrapping_sub(origin)),
+                    duration: to_duration(end.wrapping_sub(begin)),
+                }
+            })
+            .collect();
+        Ok(FrameTimings { scopes })
+    }
+}
+
+impl FrameTimings {
+    /// Returns the timings of the scopes, in the order they were opened.
+    pub fn scopes(&self) -> &[ScopeTiming] {
+        &self.scopes
+    }
+
+    /// Write the timings in the Chrome trace event JSON format, which can be
+    /// viewed in `chrome://tracing` or Perfetto.
+    pub fn write_chrome_trace(
+        &self, mut out: impl Write,
+    ) -> std::io::Result<()> {
+        write!(out, "{{\"traceEvents\":[")?;
+        for (i, scope) in self.scopes.iter().enumerate() {
+            if i != 0 {
+                write!(out, ",")?;
+            }
+            write!(out, "{{\"name\":\"")?;
+            write_json_escaped(&mut out, &scope.name)?;
+            write!(
+                out,
+                "\",\"cat\":\"gpu\",\"ph\":\"X\",\"pid\":0,\"tid\":0,\
+                \"ts\":{},\"dur\":{}}}",
+                scope.start.as_nanos() as f64 / 1000.0,
+                scope.duration.as_nanos() as f64 / 1000.0,
+            )?;
+        }
+        write!(out, "]}}")
+    }
+}
+
+fn write_json_escaped(out: &mut impl Write, s: &str) -> std::io::Result<()> {
+    for c in s.chars() {
+        match c {
+            '"' => write!(out, "\\\"")?,
+            '\\' => write!(out, "\\\\")?,
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => write!(out, "{}", c)?,
+        }
+    }
+    Ok(())
+}
+
+impl<'a> CommandRecording<'a> {
+    /// Open a profiler scope named `name`, which is closed by the next call to
+    /// [`end_scope`](Self::end_scope()). Scopes may be nested. Returns
+    /// [`Error::OutOfBounds`] if the profiler has no more room for scopes, and
+    /// [`Error::InvalidState`] if no [`reset`](Profiler::reset()) of the
+    /// profiler has been recorded.
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn begin_scope(
+        &mut self, profiler: &mut Profiler, name: &str,
+    ) -> Result<()> {
+        let query = profiler.begin(name)?;
+        self.write_timestamp(
+            PipelineStageFlags::TOP_OF_PIPE,
+            &profiler.pool,
+            query,
+        )
+    }
+    /// Close the innermost open profiler scope. Returns
+    /// [`Error::InvalidState`] if there is no open scope.
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn end_scope(&mut self, profiler: &mut Profiler) -> Result<()> {
+        let query = profiler.end()?;
+        self.write_timestamp(
+            PipelineStageFlags::BOTTOM_OF_PIPE,
+            &profiler.pool,
+            query,
+        )
+    }
+}
+
+impl<'a> RenderPassRecording<'a> {
+    /// Open a profiler scope named `name`, which is closed by the next call to
+    /// [`end_scope`](Self::end_scope()). Scopes may be nested. Returns
+    /// [`Error::OutOfBounds`] if the profiler has no more room for scopes, and
+    /// [`Error::InvalidState`] if no [`reset`](Profiler::reset()) of the
+    /// profiler has been recorded.
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn begin_scope(
+        &mut self, profiler: &mut Profiler, name: &str,
+    ) -> Result<()> {
+        let query = profiler.begin(name)?;
+        self.write_timestamp(
+            PipelineStageFlags::TOP_OF_PIPE,
+            &profiler.pool,
+            query,
+        )
+    }
+    /// Close the innermost open profiler scope. Returns
+    /// [`Error::InvalidState`] if there is no open scope.
+    #[doc = crate::man_link!(vkCmdWriteTimestamp)]
+    pub fn end_scope(&mut self, profiler: &mut Profiler) -> Result<()> {
+        let query = profiler.end()?;
+        self.write_timestamp(
+            PipelineStageFlags::BOTTOM_OF_PIPE,
+            &profiler.pool,
+            query,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::vk;
+
+    #[test]
+    fn resolve() -> vk::Result<()> {
+        let (dev, mut q) = crate::test_device()?;
+        let mut profiler = match vk::Profiler::new(&dev, 0, 4) {
+            Err(vk::Error::InvalidArgument) => return Ok(()),
+            result => result?,
+        };
+        let mut pool = vk::CommandPool::new(&dev, 0)?;
+        let buf = pool.allocate()?;
+        let mut rec = pool.begin(buf)?;
+        assert_eq!(
+            rec.begin_scope(&mut profiler, "frame"),
+            Err(vk::Error::InvalidState)
+        );
+        profiler.reset(&mut rec)?;
+        rec.begin_scope(&mut profiler, "frame")?;
+        rec.begin_scope(&mut profiler, "pass")?;
+        rec.end_scope(&mut profiler)?;
+        assert_eq!(profiler.resolve().unwrap_err(), vk::Error::InvalidState);
+        rec.end_scope(&mut profiler)?;
+        assert_eq!(rec.end_scope(&mut profiler), Err(vk::Error::InvalidState));
+        let mut buf = rec.end()?;
+        q.submit_with_fence(
+            &mut [vk::SubmitInfo {
+                commands: &mut [&mut buf],
+                ..Default::default()
+            }],
+            vk::Fence::new(&dev)?,
+        )?
+        .wait()?;
+
+        let timings = profiler.resolve()?;
+        let scopes = timings.scopes();
+        assert_eq!(scopes.len(), 2);
+        assert_eq!((scopes[0].name.as_str(), scopes[0].depth), ("frame", 0));
+        assert_eq!((scopes[1].name.as_str(), scopes[1].depth), ("pass", 1));
+        Ok(())
+    }
+
+    #[test]
+    fn chrome_trace() {
+        let timings = FrameTimings {
+            scopes: vec![
+                ScopeTiming {
+                    name: "frame".into(),
+                    depth: 0,
+                    start: Duration::ZERO,
+                    duration: Duration::from_micros(20),
+                },
+                ScopeTiming {
+                    name: "sha\"dow".into(),
+                    depth: 1,
+                    start: Duration::from_micros(5),
+                    duration: Duration::from_micros(10),
+                },
+            ],
+        };
+        let mut out = vec![];
+        timings.write_chrome_trace(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"traceEvents\":[\
+            {\"name\":\"frame\",\"cat\":\"gpu\",\"ph\":\"X\",\"pid\":0,\
+            \"tid\":0,\"ts\":0,\"dur\":20},\
+            {\"name\":\"sha\\\"dow\",\"cat\":\"gpu\",\"ph\":\"X\",\"pid\":0,\
+            \"tid\":0,\"ts\":5,\"dur\":10}]}"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2022 Google LLC
+
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ffi::c_void;
+use std::ptr::NonNull;
+
+use crate::device::Device;
+use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::types::*;
+
+/// A
+#[doc = crate::spec_link!("query pool", "17", "queries-pools")]
+#[derive(Debug)]
+pub struct QueryPool {
+    handle: Handle<VkQueryPool>,
+    query_type: QueryType,
+    len: u32,
+    values_per_query: u32,
+    device: Arc<Device>,
+}
+
+impl QueryPool {
+    /// Returns [`Error::InvalidArgument`] if `query_count` is zero, or if
+    /// `query_type` is [`QueryType::PIPELINE_STATISTICS`] and
+    /// `pipeline_statistics` is empty. Returns [`Error::MissingFeatures`] if
+    /// `query_type` is [`QueryType::PIPELINE_STATISTICS`] and the
+    /// `pipeline_statistics_query` feature is not enabled.
+    #[doc = crate::man_link!(vkCreateQueryPool)]
+    pub fn new(
+        device: &Arc<Device>, info: &QueryPoolCreateInfo,
+    ) -> Result<Arc<Self>> {
+        if info.query_count == 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let values_per_query =
+            if info.query_type == QueryType::PIPELINE_STATISTICS {
+                device.require_feature(|f| {
+                    &mut f.features.pipeline_statistics_query
+                })?;
+                if info.pipeline_statistics.is_empty() {
+                    return Err(Error::InvalidArgument);
+                }
+                info.pipeline_statistics.bits().count_ones()
+            } else {
+                1
+            };
+        let mut handle = None;
+        unsafe {
+            (device.fun.create_query_pool)(
+                device.handle(),
+                info,
+                None,
+                &mut handle,
+            )?;
+        }
+        Ok(Arc::new(Self {
+            handle: handle.unwrap(),
+            query_type: info.query_type,
+            len: info.query_count,
+            values_per_query,
+            device: device.clone(),
+        }))
+    }
+}
+
+impl Drop for QueryPool {
+    fn drop(&mut self) {
+        unsafe {
+            (self.device.fun.destroy_query_pool)(
+                self.device.handle(),
+                self.handle.borrow_mut(),
+                None,
+            )
+        }
+    }
+}
+
+impl PartialEq for QueryPool {
+    fn eq(&self, other: &Self) -> bool {
+        self.handle == other.handle
+    }
+}
+impl Eq for QueryPool {}
+
+#[allow(clippy::len_without_is_empty)]
+impl QueryPool {
+    /// Borrows the inner Vulkan handle.
+    pub fn handle(&self) -> Ref<'_, VkQueryPool> {
+        self.handle.borrow()
+    }
+    /// Returns the associated device.
+    pub fn device(&self) -> &Arc<Device> {
+        &self.device
+    }
+    /// Returns the type of queries in the pool.
+    pub fn query_type(&self) -> QueryType {
+        self.query_type
+    }
+    /// Returns the number of queries in the pool.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+    /// Returns the number of values written for each query, not including the
+    /// availability value. This is the number of statistics enabled for
+    /// pipeline statistics queries, and 1 otherwise.
+    pub fn values_per_query(&self) -> u32 {
+        self.values_per_query
+    }
+    /// Returns true if the given values are within the pool.
+    pub fn bounds_check(&self, first_query: u32, query_count: u32) -> bool {
+        self.len >= first_query && self.len - first_query >= query_count
+    }
+
+    /// Reads the results of queries starting at `first_query` into `data`,
+    /// which must hold [`values_per_query`](Self::values_per_query()) values
+    /// for each query. Returns [`Error::NotReady`] if the results are not yet
+    /// available and `flags` does not include `WAIT`. Returns
+    /// [`Error::InvalidArgument`] if `data` is empty or not a whole number of
+    /// queries, or if `flags` includes `_64` or `WITH_AVAILABILITY`. Returns
+    /// [`Error::OutOfBounds`] if the queries are out of bounds.
+    #[doc = crate::man_link!(vkGetQueryPoolResults)]
+    pub fn get_results_u32(
+        &self, first_query: u32, data: &mut [u32], flags: QueryResultFlags,
+    ) -> Result<()> {
+        self.get_results_impl(first_query, data, flags, false)
+    }
+    /// Reads the results of queries starting at `first_query` into `data`,
+    /// which must hold [`values_per_query`](Self::values_per_query()) values
+    /// for each query. Returns [`Error::NotReady`] if the results are not yet
+    /// available and `flags` does not include `WAIT`. Returns
+    /// [`Error::InvalidArgument`] if `data` is empty or not a whole number of
+    /// queries, or if `flags` includes `_64` or `WITH_AVAILABILITY`. Returns
+    /// [`Error::OutOfBounds`] if the queries are out of bounds.
+    #[doc = crate::man_link!(vkGetQueryPoolResults)]
+    pub fn get_results_u64(
+        &self, first_query: u32, data: &mut [u64], flags: QueryResultFlags,
+    ) -> Result<()> {
+        self.get_results_impl(first_query, data, flags, false)
+    }
+    /// Reads the results of queries starting at `first_query` into `data`,
+    /// which must hold [`values_per_query`](Self::values_per_query()) values
+    /// followed by an availability value for each query. The availability
+    /// value is nonzero if the query's results are available; results for
+    /// unavailable queries are not written unless `flags` includes `PARTIAL`.
+    /// Returns [`Error::InvalidArgument`] if `data` is empty or not a whole
+    /// number of queries, or if `flags` includes `_64` or `WITH_AVAILABILITY`.
+    /// Returns [`Error::OutOfBounds`] if the queries are out of bounds.
+    #[doc = crate::man_link!(vkGetQueryPoolResults)]
+    pub fn get_results_with_availability_u32(
+        &self, first_query: u32, data: &mut [u32], flags: QueryResultFlags,
+    ) -> Result<()> {
+        self.get_results_impl(first_query, data, flags, true)
+    }
+    /// Reads the results of queries starting at `first_query` into `data`,
+    /// which must hold [`values_per_query`](Self::values_per_query()) values
+    /// followed by an availability value for each query. The availability
+    /// value is nonzero if the query's results are available; results for
+    /// unavailable queries are not written unless `flags` includes `PARTIAL`.
+    /// Returns [`Error::InvalidArgument`] if `data` is empty or not a whole
+    /// number of queries, or if `flags` includes `_64` or `WITH_AVAILABILITY`.
+    /// Returns [`Error::OutOfBounds`] if the queries are out of bounds.
+    #[doc = crate::man_link!(vkGetQueryPoolResults)]
+    pub fn get_results_with_availability_u64(
+        &self, first_query: u32, data: &mut [u64], flags: QueryResultFlags,
+    ) -> Result<()> {
+        self.get_results_impl(first_query, data, flags, true)
+    }
+
+    fn get_results_impl<T: QueryResult>(
+        &self, first_query: u32, data: &mut [T], flags: QueryResultFlags,
+        with_availability: bool,
+    ) -> Result<()> {
+        if flags.intersects(
+            QueryResultFlags::_64 | QueryResultFlags::WITH_AVAILABILITY,
+        ) {
+            return Err(Error::InvalidArgument);
+        }
+        let mut flags = flags | T::FLAGS;
+        let mut stride = self.values_per_query as usize;
+        if with_availability {
+            flags |= QueryResultFlags::WITH_AVAILABILITY;
+            stride += 1;
+        }
+        if data.is_empty() || data.len() % stride != 0 {
+            return Err(Error::InvalidArgument);
+        }
+        let query_count = (data.len() / stride) as u32;
+        if !self.bounds_check(first_query, query_count) {
+            return Err(Error::OutOfBounds);
+        }
+        let result = unsafe {
+            (self.device.fun.get_query_pool_results)(
+                self.device.handle(),
+                self.handle(),
+                first_query,
+                query_count,
+                std::mem::size_of_val(data),
+                NonNull::from(&mut *data).cast::<c_void>(),
+                (stride * std::mem::size_of::<T>()) as u64,
+                flags,
+            )
+        };
+        match result.map_err(Error::from) {
+            // The availability values report which results are ready
+            Err(Error::NotReady) if with_availability => Ok(()),
+            other => other,
+        }
+    }
+}
+
+/// Types which can hold the result of a query.
+trait QueryResult {
+    const FLAGS: QueryResultFlags;
+}
+impl QueryResult for u32 {
+    const FLAGS: QueryResultFlags = QueryResultFlags::empty();
+}
+impl QueryResult for u64 {
+    const FLAGS: QueryResultFlags = QueryResultFlags::_64;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vk;
+
+    #[test]
+    fn active_queries() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let pool = vk::QueryPool::new(
+            &dev,
+            &vk::QueryPoolCreateInfo {
+                query_type: vk::QueryType::OCCLUSION,
+                query_count: 2,
+                ..Default::default()
+            },
+        )?;
+        let mut cmd_pool = vk::CommandPool::new(&dev, 0)?;
+        let buf = cmd_pool.allocate()?;
+        let mut rec = cmd_pool.begin(buf)?;
+        rec.reset_query_pool(&pool, 0, 2)?;
+        assert_eq!(rec.end_query(&pool, 0), Err(vk::Error::InvalidState));
+        rec.begin_query(&pool, 0, Default::default())?;
+        assert_eq!(
+            rec.begin_query(&pool, 0, Default::default()),
+            Err(vk::Error::InvalidState)
+        );
+        assert_eq!(rec.end_query(&pool, 1), Err(vk::Error::InvalidState));
+        rec.end_query(&pool, 0)?;
+        assert_eq!(rec.end_query(&pool, 0), Err(vk::Error::InvalidState));
+        let _buf = rec.end()?;
+        Ok(())
+    }
+
+    #[test]
+    fn results_bounds() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        let pool = vk::QueryPool::new(
+            &dev,
+            &vk::QueryPoolCreateInfo {
+                query_type: vk::QueryType::TIMESTAMP,
+                query_count: 4,
+                ..Default::default()
+            },
+        )?;
+        let mut data = [0u64; 8];
+        assert_eq!(
+            pool.get_results_u64(0, &mut data[..5], Default::default()),
+            Err(vk::Error::OutOfBounds)
+        );
+        assert_eq!(
+            pool.get_results_with_availability_u64(
+                3,
+                &mut data[..4],
+                Default::default()
+            ),
+            Err(vk::Error::OutOfBounds)
+        );
+        assert_eq!(
+            pool.get_results_with_availability_u64(
+                0,
+                &mut data[..3],
+                Default::default()
+            ),
+            Err(vk::Error::InvalidArgument)
+        );
+        assert_eq!(
+            pool.get_results_u64(0, &mut data[..1], vk::QueryResultFlags::_64),
+            Err(vk::Error::InvalidArgument)
+        );
+        Ok(())
+    }
+}
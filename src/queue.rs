@@ -8,6 +8,8 @@
 
 use std::fmt::Debug;
 
+use bumpalo::collections::Vec as BumpVec;
+
 use crate::cleanup_queue::CleanupQueue;
 use crate::command_buffer::CommandBuffer;
 use crate::device::Device;
@@ -15,7 +17,7 @@ use crate::error::{Error, Result};
 use crate::exclusive::Exclusive;
 use crate::fence::{Fence, PendingFence};
 use crate::ffi::Array;
-use crate::semaphore::{Semaphore, SemaphoreSignaller};
+use crate::semaphore::{Semaphore, SemaphoreSignaller, TimelineSemaphore};
 use crate::types::*;
 use crate::vk::PipelineStageFlags;
 
@@ -31,10 +33,14 @@ use crate::vk::PipelineStageFlags;
 /// pools) until the queue is done with them. This happens when either
 /// * [`Queue::wait_idle`] is called.
 /// * [`PendingFence::wait`](PendingFence::wait()) is called on a fence passed
-/// to [`Queue::submit_with_fence`](Queue::submit_with_fence()).
-/// * A semaphore is passed to `submit` in [`SubmitInfo::signal`], then passed
-/// to another queue in [`SubmitInfo::wait`], (and so on) and on the last queue
-/// one of the first two things is done.
+///   to [`Queue::submit_with_fence`](Queue::submit_with_fence()).
+/// * A semaphore is passed to `submit` in [`SubmitInfo::signal`], then
+///   passed to another queue in [`SubmitInfo::wait`], (and so on) and on the
+///   last queue one of the first two things is done.
+/// * A [`TimelineSemaphore`] is passed to `submit` in
+///   [`SubmitInfo::timeline_signal`], and the host observes a value greater
+///   than or equal to the one signalled, with [`TimelineSemaphore::wait`] or
+///   [`TimelineSemaphore::counter_value`].
 #[derive(Debug)]
 pub struct Queue {
     handle: Handle<VkQueue>,
@@ -97,12 +103,19 @@ pub struct SubmitInfo<'a> {
     pub wait: &'a mut [(&'a mut Semaphore, PipelineStageFlags)],
     pub commands: &'a mut [&'a mut CommandBuffer],
     pub signal: &'a mut [&'a mut Semaphore],
+    /// Timeline semaphores to wait on, and the value to wait for.
+    pub timeline_wait:
+        &'a mut [(&'a mut TimelineSemaphore, u64, PipelineStageFlags)],
+    /// Timeline semaphores to signal, and the value to set them to.
+    pub timeline_signal: &'a mut [(&'a mut TimelineSemaphore, u64)],
 }
 
 impl Queue {
     /// Returns [`Error::InvalidArgument`] if any semaphore in `signal` already
     /// has a signal operation pending, or if any semaphore in `wait` does not,
-    /// or if any command buffer is not in the executable state.
+    /// if any value in `timeline_signal` is not greater than the last value
+    /// signalled for that semaphore, or if any command buffer is not in the
+    /// executable state.
     #[doc = crate::man_link!(vkQueueSubmit)]
     pub fn submit_with_fence(
         &mut self, infos: &mut [SubmitInfo<'_>], mut fence: Fence,
@@ -113,7 +126,9 @@ impl Queue {
 
     /// Returns [`Error::InvalidArgument`] if any semaphore in `signal` already
     /// has a signal operation pending, or if any semaphore in `wait` does not,
-    /// or if any command buffer is not in the executable state.
+    /// if any value in `timeline_signal` is not greater than the last value
+    /// signalled for that semaphore, or if any command buffer is not in the
+    /// executable state.
     #[doc = crate::man_link!(vkQueueSubmit)]
     pub fn submit(&mut self, infos: &mut [SubmitInfo<'_>]) -> Result<()> {
         self.submit_impl(infos, None)
@@ -133,6 +148,11 @@ impl Queue {
                     return Err(Error::InvalidArgument);
                 }
             }
+            for (sem, value) in info.timeline_signal.iter() {
+                if *value <= sem.last_signal_value() {
+                    return Err(Error::InvalidArgument);
+                }
+            }
         }
 
         let scratch = self.scratch.get_mut();
@@ -150,16 +170,55 @@ impl Queue {
                 commands.push(c.mut_handle()?);
             }
             recordings.push(info_recordings);
-            let wait_semaphores = scratch.alloc_slice_fill_iter(
-                info.wait.iter().map(|(sem, _)| sem.handle()),
-            );
-            let wait_stage_masks = scratch.alloc_slice_fill_iter(
-                info.wait.iter().map(|(_, mask)| *mask), //
-            );
-            let signal_semaphores = scratch.alloc_slice_fill_iter(
-                info.signal.iter().map(|sem| sem.handle()),
-            );
+            let wait_semaphores = BumpVec::from_iter_in(
+                info.wait.iter().map(|(sem, _)| sem.handle()).chain(
+                    info.timeline_wait.iter().map(|(sem, _, _)| sem.handle()),
+                ),
+                scratch,
+            )
+            .into_bump_slice();
+            let wait_stage_masks = BumpVec::from_iter_in(
+                info.wait
+                    .iter()
+                    .map(|(_, mask)| *mask)
+                    .chain(info.timeline_wait.iter().map(|(_, _, mask)| *mask)),
+                scratch,
+            )
+            .into_bump_slice();
+            let signal_semaphores = BumpVec::from_iter_in(
+                info.signal.iter().map(|sem| sem.handle()).chain(
+                    info.timeline_signal.iter().map(|(sem, _)| sem.handle()),
+                ),
+                scratch,
+            )
+            .into_bump_slice();
+            let mut next = None;
+            if !info.timeline_wait.is_empty()
+                || !info.timeline_signal.is_empty()
+            {
+                // Binary semaphores ignore their values
+                let wait_values = BumpVec::from_iter_in(
+                    info.wait.iter().map(|_| 0).chain(
+                        info.timeline_wait.iter().map(|(_, value, _)| *value),
+                    ),
+                    scratch,
+                )
+                .into_bump_slice();
+                let signal_values = BumpVec::from_iter_in(
+                    info.signal.iter().map(|_| 0).chain(
+                        info.timeline_signal.iter().map(|(_, value)| *value),
+                    ),
+                    scratch,
+                )
+                .into_bump_slice();
+                next = Some(&*scratch.alloc(TimelineSemaphoreSubmitInfo {
+                    wait_semaphore_values: wait_values.into(),
+                    signal_semaphore_values: signal_values.into(),
+                    ..Default::default()
+                }));
+            }
             vk_infos.push(VkSubmitInfo {
+                next,
                 wait_semaphores: wait_semaphores.into(),
                 wait_stage_masks: Array::from_slice(wait_stage_masks),
                 command_buffers: commands.into_bump_slice().into(),
@@ -184,6 +243,12 @@ impl Queue {
                 self.resources.push(sem.take_signaller());
                 self.resources.push(sem.inner.clone());
             }
+            for (sem, value, _) in info.timeline_wait.iter_mut() {
+                self.resources.extend(
+                    sem.take_signallers(*value).map(|c| Arc::new(c.raii())),
+                );
+                self.resources.push(sem.inner.clone());
+            }
             self.resources.extend(recs.into_iter());
             for command in info.commands.iter() {
                 self.resources.push(command.lock_self());
@@ -194,6 +259,10 @@ impl Queue {
                 ));
                 self.resources.push(sem.inner.clone());
             }
+            for (sem, value) in info.timeline_signal.iter_mut() {
+                sem.add_signal(*value, self.resources.new_cleanup());
+                self.resources.push(sem.inner.clone());
+            }
         }
         Ok(())
     }
@@ -252,6 +321,19 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn timeline_feature() -> vk::Result<()> {
+        let (dev, _) = crate::test_device()?;
+        match vk::TimelineSemaphore::new(&dev, 0) {
+            Err(vk::Error::MissingFeatures(names)) => assert_eq!(
+                names.iter().collect::<Vec<_>>(),
+                ["timeline_semaphore"]
+            ),
+            _ => panic!("expected MissingFeatures"),
+        }
+        Ok(())
+    }
+
     #[test]
     fn signaller() -> vk::Result<()> {
         let (dev, mut q) = crate::test_device()?;
@@ -295,6 +377,51 @@ mod test {
         Ok(())
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn external_sync_fd() -> vk::Result<()> {
+        let (dev, mut q) = crate::test_device_with_extensions(&[
+            vk::ext::EXTERNAL_SEMAPHORE_FD,
+            vk::ext::EXTERNAL_FENCE_FD,
+        ])?;
+        let sync_fd = vk::ExternalSemaphoreHandleTypeFlags::SYNC_FD;
+        let mut sem = vk::Semaphore::new_exportable(&dev, sync_fd)?;
+        assert!(sem.export_fd(sync_fd).is_err());
+        let fence = q.submit_with_fence(
+            &mut [vk::SubmitInfo {
+                signal: &mut [&mut sem],
+                ..Default::default()
+            }],
+            vk::Fence::new(&dev)?,
+        )?;
+        let fd = sem.export_fd(sync_fd)?;
+        fence.wait()?;
+
+        let mut imported = vk::Semaphore::new(&dev)?;
+        imported.import_fd(sync_fd, fd, vk::SemaphoreImportFlags::TEMPORARY)?;
+        q.submit_with_fence(
+            &mut [vk::SubmitInfo {
+                wait: &mut [(&mut imported, Default::default())],
+                ..Default::default()
+            }],
+            vk::Fence::new(&dev)?,
+        )?
+        .wait()?;
+
+        let opaque = vk::ExternalFenceHandleTypeFlags::OPAQUE_FD;
+        let fence = vk::Fence::new_exportable(&dev, opaque)?;
+        let fd = fence.export_fd(opaque)?;
+        assert_eq!(
+            vk::Fence::new(&dev)?
+                .import_fd(opaque, fd, Default::default())
+                .err()
+                .map(|e| e.0),
+            Some(vk::Error::InvalidArgument)
+        );
+        q.submit_with_fence(&mut [], fence)?.wait()?;
+        Ok(())
+    }
+
     #[test]
     fn cross_queue_sync() -> vk::Result<()> {
         let inst = vk::Instance::new(&Default::default())?;
@@ -338,6 +465,7 @@ mod test {
                 wait: &mut [],
                 commands: &mut [&mut buf1],
                 signal: &mut [&mut sem],
+                ..Default::default()
             },
             vk::SubmitInfo { commands: &mut [&mut buf2], ..Default::default() },
         ])?;
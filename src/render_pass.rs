@@ -62,6 +62,17 @@ impl RenderPass {
     pub fn compatible(&self, other: &Self) -> bool {
         std::ptr::eq(self, other) || self.compat == other.compat
     }
+    /// Returns the number of color attachments in `subpass`.
+    pub(crate) fn num_color_attachments(&self, subpass: u32) -> u32 {
+        self.compat.subpasses[subpass as usize].color_attachments.len() as u32
+    }
+    /// Returns true if `subpass` has a depth/stencil attachment.
+    pub(crate) fn has_depth_stencil_attachment(&self, subpass: u32) -> bool {
+        self.compat.subpasses[subpass as usize]
+            .depth_stencil_attachments
+            .iter()
+            .any(|a| a.is_some())
+    }
 }
 
 impl Drop for RenderPass {
@@ -7,7 +7,7 @@
 // except according to those terms.
 
 use crate::device::Device;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::types::*;
 
 /// A
@@ -19,10 +19,20 @@ pub struct Sampler {
 }
 
 impl Sampler {
+    /// Returns [`Error::MissingFeatures`] if `info.anisotropy_enable` is true
+    /// and the `sampler_anisotropy` feature is not enabled, and
+    /// [`Error::LimitExceeded`] if `info.max_anisotropy` is greater than
+    /// [`max_sampler_anisotropy`](PhysicalDeviceLimits::max_sampler_anisotropy).
     #[doc = crate::man_link!(vkCreateSampler)]
     pub fn new(
         device: &Arc<Device>, info: &SamplerCreateInfo,
     ) -> Result<Arc<Self>> {
+        if info.anisotropy_enable.as_bool() {
+            device.require_feature(|f| &mut f.features.sampler_anisotropy)?;
+            if info.max_anisotropy > device.limits().max_sampler_anisotropy {
+                return Err(Error::LimitExceeded);
+            }
+        }
         device.increment_sampler_alloc_count()?;
         let mut handle = None;
         let result = unsafe {
@@ -6,9 +6,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use std::collections::VecDeque;
+use std::mem::transmute;
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+
 use crate::cleanup_queue::Cleanup;
 use crate::device::Device;
-use crate::error::Result;
+use crate::enums::*;
+use crate::error::{Error, Result};
+use crate::ffi::{c_void, NonNull};
 use crate::image::Image;
 use crate::types::*;
 
@@ -17,12 +24,15 @@ use crate::types::*;
 pub struct Semaphore {
     pub(crate) signaller: Option<SemaphoreSignaller>,
     pub(crate) inner: Arc<SemaphoreRAII>,
+    export_handle_types: ExternalSemaphoreHandleTypeFlags,
 }
 
 #[derive(Debug)]
 pub(crate) enum SemaphoreSignaller {
     Swapchain(Arc<Image>),
     Queue(Cleanup),
+    /// A payload imported from outside of Vulkan.
+    Imported,
 }
 
 pub(crate) struct SemaphoreRAII {
@@ -30,20 +40,118 @@ pub(crate) struct SemaphoreRAII {
     device: Arc<Device>,
 }
 
+/// A timeline
+#[doc = crate::spec_link!("semaphore", "7", "synchronization-semaphores")]
+///
+/// Requires Vulkan 1.2 and the `timeline_semaphore` feature. Values signalled
+/// in [`SubmitInfo::timeline_signal`](crate::vk::SubmitInfo::timeline_signal)
+/// hold the resources submitted to the queue until the host observes the
+/// semaphore's counter reaching that value, either through
+/// [`wait`](Self::wait()) or [`counter_value`](Self::counter_value()), or until
+/// another queue waits for the value and that queue's resources are released.
+pub struct TimelineSemaphore {
+    pub(crate) inner: Arc<SemaphoreRAII>,
+    fun: TimelineSemaphoreFn,
+    /// Signal operations not yet observed by the host, in increasing order.
+    pending: VecDeque<(u64, Cleanup)>,
+    last_signal: u64,
+}
+
+#[derive(Clone)]
+struct TimelineSemaphoreFn {
+    get_semaphore_counter_value: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        Ref<VkSemaphore>,
+        &mut u64,
+    ) -> VkResult,
+    wait_semaphores: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        &SemaphoreWaitInfo,
+        u64,
+    ) -> VkResult,
+    signal_semaphore: unsafe extern "system" fn(
+        Ref<VkDevice>,
+        &SemaphoreSignalInfo,
+    ) -> VkResult,
+}
+
+impl TimelineSemaphoreFn {
+    /// Returns None if the device does not support Vulkan 1.2.
+    fn new(dev: &Device) -> Option<Self> {
+        unsafe {
+            Some(Self {
+                get_semaphore_counter_value: transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkDevice>,
+                        Ref<VkSemaphore>,
+                        &mut u64,
+                    ) -> VkResult,
+                >(
+                    dev.try_get_proc_addr("vkGetSemaphoreCounterValue\0")?,
+                ),
+                wait_semaphores: transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkDevice>,
+                        &SemaphoreWaitInfo,
+                        u64,
+                    ) -> VkResult,
+                >(
+                    dev.try_get_proc_addr("vkWaitSemaphores\0")?
+                ),
+                signal_semaphore: transmute::<
+                    NonNull<c_void>,
+                    unsafe extern "system" fn(
+                        Ref<VkDevice>,
+                        &SemaphoreSignalInfo,
+                    ) -> VkResult,
+                >(
+                    dev.try_get_proc_addr("vkSignalSemaphore\0")?,
+                ),
+            })
+        }
+    }
+}
+
 impl Semaphore {
     #[doc = crate::man_link!(vkCreateSemaphore)]
     pub fn new(device: &Arc<Device>) -> Result<Self> {
+        Self::create(device, Default::default())
+    }
+
+    /// Create a semaphore which can be exported as any of `handle_types`.
+    /// Requires Vulkan 1.1 or VK_KHR_external_semaphore.
+    #[doc = crate::man_link!(VkExportSemaphoreCreateInfo)]
+    pub fn new_exportable(
+        device: &Arc<Device>, handle_types: ExternalSemaphoreHandleTypeFlags,
+    ) -> Result<Self> {
+        Self::create(device, handle_types)
+    }
+
+    fn create(
+        device: &Arc<Device>,
+        export_handle_types: ExternalSemaphoreHandleTypeFlags,
+    ) -> Result<Self> {
+        let export_info: ExportSemaphoreCreateInfo =
+            ExportSemaphoreCreateInfo {
+                handle_types: export_handle_types,
+                ..Default::default()
+            };
+        let next = (!export_handle_types.is_empty())
+            .then(|| NonNull::from(&export_info).cast::<c_void>());
         let mut handle = None;
         unsafe {
             (device.fun.create_semaphore)(
                 device.handle(),
-                &Default::default(),
+                &SemaphoreCreateInfo { next, ..Default::default() },
                 None,
                 &mut handle,
             )?;
         }
         Ok(Self {
             signaller: None,
+            export_handle_types,
             inner: Arc::new(SemaphoreRAII {
                 handle: handle.unwrap(),
                 device: device.clone(),
@@ -52,6 +160,44 @@ impl Semaphore {
     }
 }
 
+impl TimelineSemaphore {
+    /// Create a timeline semaphore with a counter value of `initial_value`.
+    /// Returns [`Error::MissingFeatures`] if the `timeline_semaphore` feature
+    /// is not enabled, which requires Vulkan 1.2.
+    #[doc = crate::man_link!(vkCreateSemaphore)]
+    pub fn new(device: &Arc<Device>, initial_value: u64) -> Result<Self> {
+        device.require_feature(|f| &mut f.vulkan_1_2.timeline_semaphore)?;
+        let fun = TimelineSemaphoreFn::new(device)
+            .ok_or(Error::ExtensionNotPresent)?;
+        let type_info: SemaphoreTypeCreateInfo = SemaphoreTypeCreateInfo {
+            semaphore_type: SemaphoreType::TIMELINE,
+            initial_value,
+            ..Default::default()
+        };
+        let mut handle = None;
+        unsafe {
+            (device.fun.create_semaphore)(
+                device.handle(),
+                &SemaphoreCreateInfo {
+                    next: Some(NonNull::from(&type_info).cast()),
+                    ..Default::default()
+                },
+                None,
+                &mut handle,
+            )?;
+        }
+        Ok(Self {
+            inner: Arc::new(SemaphoreRAII {
+                handle: handle.unwrap(),
+                device: device.clone(),
+            }),
+            fun,
+            pending: VecDeque::new(),
+            last_signal: initial_value,
+        })
+    }
+}
+
 impl Drop for Semaphore {
     /// **Warning:** If a semaphore is passed to
     /// [`SwapchainKHR::acquire_next_image`](crate::vk::ext::SwapchainKHR::acquire_next_image())
@@ -101,6 +247,247 @@ impl Semaphore {
         match self.signaller.take().unwrap() {
             SemaphoreSignaller::Queue(cleanup) => Arc::new(cleanup.raii()),
             SemaphoreSignaller::Swapchain(image) => image,
+            SemaphoreSignaller::Imported => Arc::new(()),
+        }
+    }
+
+    /// Export the semaphore's payload as a file descriptor of type
+    /// `handle_type`, which must be one of the types the semaphore was
+    /// [created](Self::new_exportable()) with. Requires
+    /// VK_KHR_external_semaphore_fd, and returns
+    /// [`Error::ExtensionNotPresent`] if it is not enabled.
+    ///
+    /// Exporting a `SYNC_FD` moves the semaphore's pending signal to the file
+    /// descriptor, leaving the semaphore unsignalled. Returns
+    /// [`Error::InvalidState`] if there is no pending signal or if it comes from
+    /// [`SwapchainKHR::acquire_next_image`](crate::vk::ext::SwapchainKHR::acquire_next_image()),
+    /// and [`Error::InvalidArgument`] if `handle_type` is not a single
+    /// exportable type.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(vkGetSemaphoreFdKHR)]
+    pub fn export_fd(
+        &mut self, handle_type: ExternalSemaphoreHandleTypeFlags,
+    ) -> Result<OwnedFd> {
+        if handle_type.bits().count_ones() != 1
+            || !self.export_handle_types.contains(handle_type)
+        {
+            return Err(Error::InvalidArgument);
+        }
+        let sync_fd = handle_type == ExternalSemaphoreHandleTypeFlags::SYNC_FD;
+        if sync_fd
+            && !matches!(
+                self.signaller,
+                Some(
+                    SemaphoreSignaller::Queue(_) | SemaphoreSignaller::Imported
+                )
+            )
+        {
+            return Err(Error::InvalidState);
+        }
+        let device = &self.inner.device;
+        let get_semaphore_fd: unsafe extern "system" fn(
+            Ref<VkDevice>,
+            &SemaphoreGetFdInfoKHR,
+            &mut RawFd,
+        ) -> VkResult = unsafe {
+            transmute(
+                device
+                    .try_get_proc_addr("vkGetSemaphoreFdKHR\0")
+                    .ok_or(Error::ExtensionNotPresent)?,
+            )
+        };
+        let mut fd = -1;
+        unsafe {
+            (get_semaphore_fd)(
+                device.handle(),
+                &SemaphoreGetFdInfoKHR {
+                    stype: Default::default(),
+                    next: Default::default(),
+                    semaphore: self.handle(),
+                    handle_type,
+                },
+                &mut fd,
+            )?;
+        }
+        if sync_fd {
+            // The signalling queue holds its own resources until it is done.
+            self.signaller = None;
         }
+        Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+
+    /// Import a payload from a file descriptor of type `handle_type`. The file
+    /// descriptor is owned by the implementation if the import succeeds, and
+    /// closed if it fails. A `SYNC_FD` must be imported with
+    /// [`SemaphoreImportFlags::TEMPORARY`]. Requires
+    /// VK_KHR_external_semaphore_fd, and returns
+    /// [`Error::ExtensionNotPresent`] if it is not enabled.
+    ///
+    /// Afterwards, the semaphore is treated as having a pending signal, so it
+    /// can be waited on in [`Queue::submit`](crate::vk::Queue::submit()).
+    /// Returns [`Error::InvalidState`] if the semaphore already has a pending
+    /// signal or is in use by a queue, and [`Error::InvalidArgument`] if
+    /// `handle_type` is not a single type or the flags are wrong for it.
+    #[cfg(unix)]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[doc = crate::man_link!(vkImportSemaphoreFdKHR)]
+    pub fn import_fd(
+        &mut self, handle_type: ExternalSemaphoreHandleTypeFlags, fd: OwnedFd,
+        flags: SemaphoreImportFlags,
+    ) -> Result<()> {
+        if handle_type.bits().count_ones() != 1
+            || (handle_type == ExternalSemaphoreHandleTypeFlags::SYNC_FD
+                && !flags.contains(SemaphoreImportFlags::TEMPORARY))
+        {
+            return Err(Error::InvalidArgument);
+        }
+        if self.signaller.is_some() || Arc::get_mut(&mut self.inner).is_none() {
+            return Err(Error::InvalidState);
+        }
+        let device = self.inner.device.clone();
+        let import_semaphore_fd: unsafe extern "system" fn(
+            Ref<VkDevice>,
+            &ImportSemaphoreFdInfoKHR,
+        )
+            -> VkResult = unsafe {
+            transmute(
+                device
+                    .try_get_proc_addr("vkImportSemaphoreFdKHR\0")
+                    .ok_or(Error::ExtensionNotPresent)?,
+            )
+        };
+        unsafe {
+            (import_semaphore_fd)(
+                device.handle(),
+                &ImportSemaphoreFdInfoKHR {
+                    stype: Default::default(),
+                    next: Default::default(),
+                    semaphore: self.mut_handle(),
+                    flags,
+                    handle_type,
+                    fd: fd.as_raw_fd(),
+                },
+            )?;
+        }
+        // The implementation owns it now.
+        let _ = fd.into_raw_fd();
+        self.signaller = Some(SemaphoreSignaller::Imported);
+        Ok(())
+    }
+}
+
+impl TimelineSemaphore {
+    /// Borrows the inner Vulkan handle.
+    pub fn handle(&self) -> Ref<'_, VkSemaphore> {
+        self.inner.handle.borrow()
+    }
+    /// Borrows the inner Vulkan handle.
+    pub fn mut_handle(&mut self) -> Mut<'_, VkSemaphore> {
+        // Safe because the outer structure is mutably borrowed, and handle is
+        // private.
+        unsafe { self.inner.handle.borrow_mut_unchecked() }
+    }
+    /// Returns the associated device.
+    pub fn device(&self) -> &Arc<Device> {
+        &self.inner.device
+    }
+    /// Returns the largest value that has been signalled or submitted to be
+    /// signalled.
+    pub fn last_signal_value(&self) -> u64 {
+        self.last_signal
+    }
+
+    /// Returns the current value of the semaphore's counter, and releases the
+    /// resources of any submissions which signalled a value less than or equal
+    /// to it.
+    #[doc = crate::man_link!(vkGetSemaphoreCounterValue)]
+    pub fn counter_value(&mut self) -> Result<u64> {
+        let mut value = 0;
+        unsafe {
+            (self.fun.get_semaphore_counter_value)(
+                self.inner.device.handle(),
+                self.handle(),
+                &mut value,
+            )?;
+        }
+        self.observed(value);
+        Ok(value)
+    }
+
+    /// Waits for the semaphore's counter to reach `value`, for up to `timeout`
+    /// nanoseconds, and releases the resources of any submissions which
+    /// signalled a value less than or equal to it. Returns [`Error::Timeout`]
+    /// if the timeout expires first.
+    #[doc = crate::man_link!(vkWaitSemaphores)]
+    pub fn wait(&mut self, value: u64, timeout: u64) -> Result<()> {
+        unsafe {
+            (self.fun.wait_semaphores)(
+                self.inner.device.handle(),
+                &SemaphoreWaitInfo {
+                    stype: Default::default(),
+                    next: Default::default(),
+                    flags: Default::default(),
+                    semaphores: (&[self.handle()]).into(),
+                    values: (&[value]).into(),
+                },
+                timeout,
+            )?;
+        }
+        self.observed(value);
+        Ok(())
+    }
+
+    /// Sets the semaphore's counter to `value` from the host. Returns
+    /// [`Error::InvalidArgument`] if `value` is not greater than the current
+    /// value, or if a signal operation submitted to a queue has not yet been
+    /// observed to complete.
+    #[doc = crate::man_link!(vkSignalSemaphore)]
+    pub fn signal(&mut self, value: u64) -> Result<()> {
+        let current = self.counter_value()?;
+        if value <= current || !self.pending.is_empty() {
+            return Err(Error::InvalidArgument);
+        }
+        unsafe {
+            (self.fun.signal_semaphore)(
+                self.inner.device.handle(),
+                &SemaphoreSignalInfo {
+                    stype: Default::default(),
+                    next: Default::default(),
+                    semaphore: self.handle(),
+                    value,
+                },
+            )?;
+        }
+        self.last_signal = self.last_signal.max(value);
+        Ok(())
+    }
+
+    /// Releases the resources of signal operations up to `value`.
+    fn observed(&mut self, value: u64) {
+        while let Some((signal, cleanup)) = self.pending.front() {
+            if *signal > value {
+                break;
+            }
+            cleanup.cleanup();
+            self.pending.pop_front();
+        }
+    }
+
+    /// Record a signal operation submitted to a queue. `resources` is cleaned
+    /// up when the signal is observed.
+    pub(crate) fn add_signal(&mut self, value: u64, resources: Cleanup) {
+        self.last_signal = value;
+        self.pending.push_back((value, resources));
+    }
+
+    /// Take the cleanups of the signal operations which a wait for `value`
+    /// depends on.
+    pub(crate) fn take_signallers(
+        &mut self, value: u64,
+    ) -> impl Iterator<Item = Cleanup> + '_ {
+        let count =
+            self.pending.iter().take_while(|(s, _)| *s <= value).count();
+        self.pending.drain(..count).map(|(_, cleanup)| cleanup)
     }
 }
@@ -172,6 +172,7 @@ raw_handle!(VkQueue(NonNullDispatchableHandle));
 raw_handle!(VkDeviceMemory(NonNullNonDispatchableHandle));
 raw_handle!(VkSemaphore(NonNullNonDispatchableHandle));
 raw_handle!(VkFence(NonNullNonDispatchableHandle));
+raw_handle!(VkEvent(NonNullNonDispatchableHandle));
 raw_handle!(VkSampler(NonNullNonDispatchableHandle));
 raw_handle!(VkDescriptorSetLayout(NonNullNonDispatchableHandle));
 raw_handle!(VkDescriptorPool(NonNullNonDispatchableHandle));
@@ -188,14 +189,16 @@ raw_handle!(VkRenderPass(NonNullNonDispatchableHandle));
 raw_handle!(VkShaderModule(NonNullNonDispatchableHandle));
 raw_handle!(VkCommandPool(NonNullNonDispatchableHandle));
 raw_handle!(VkCommandBuffer(NonNullNonDispatchableHandle));
+raw_handle!(VkQueryPool(NonNullNonDispatchableHandle));
 raw_handle!(VkSurfaceKHR(NonNullNonDispatchableHandle));
 raw_handle!(VkSwapchainKHR(NonNullNonDispatchableHandle));
+raw_handle!(VkDebugUtilsMessengerEXT(NonNullNonDispatchableHandle));
 
 /// u32 with only one allowed value
 macro_rules! structure_type {
     ($name: ident, $value: literal) => {
         #[repr(u32)]
-        #[derive(Debug)]
+        #[derive(Debug, Clone, Copy)]
         /// [Structure type](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VkStructureType.html) constant
         #[doc = concat!("(", stringify!($value), ")")]
         pub enum $name {
@@ -211,6 +214,13 @@ macro_rules! structure_type {
     };
 }
 
+/// The members which begin every extension structure.
+#[repr(C)]
+pub(crate) struct BaseInStructure {
+    pub stype: u32,
+    pub next: Option<NonNull<BaseInStructure>>,
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Extent2D {
@@ -324,6 +334,31 @@ impl Default for ClearValue {
     }
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+#[doc = crate::man_link!(VkClearAttachment)]
+pub struct ClearAttachment {
+    pub aspect_mask: ImageAspectFlags,
+    pub color_attachment: u32,
+    pub clear_value: ClearValue,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+#[doc = crate::man_link!(VkClearRect)]
+pub struct ClearRect {
+    pub rect: Rect2D,
+    pub base_array_layer: u32,
+    pub layer_count: u32,
+}
+
+impl Default for ClearRect {
+    /// An empty rectangle on the first layer
+    fn default() -> Self {
+        Self { rect: Default::default(), base_array_layer: 0, layer_count: 1 }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 #[doc = crate::man_link!(VkImageSubresourceRange)]
@@ -380,6 +415,28 @@ pub struct ImageBlit {
     pub dst_offsets: [Offset3D; 2],
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+#[doc = crate::man_link!(VkImageCopy)]
+pub struct ImageCopy {
+    pub src_subresource: ImageSubresourceLayers,
+    pub src_offset: Offset3D,
+    pub dst_subresource: ImageSubresourceLayers,
+    pub dst_offset: Offset3D,
+    pub extent: Extent3D,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+#[doc = crate::man_link!(VkImageResolve)]
+pub struct ImageResolve {
+    pub src_subresource: ImageSubresourceLayers,
+    pub src_offset: Offset3D,
+    pub dst_subresource: ImageSubresourceLayers,
+    pub dst_offset: Offset3D,
+    pub extent: Extent3D,
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Default)]
 #[doc = crate::man_link!(VkComponentMapping)]
@@ -426,6 +483,27 @@ pub struct PhysicalDeviceMemoryProperties {
     pub memory_heaps: InlineSlice<MemoryHeap, 16>,
 }
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkPhysicalDeviceMemoryProperties2)]
+pub struct PhysicalDeviceMemoryProperties2<Next = Null> {
+    pub stype: PhysicalDeviceMemoryProperties2Type,
+    pub next: Next,
+    pub memory_properties: PhysicalDeviceMemoryProperties,
+}
+structure_type!(PhysicalDeviceMemoryProperties2Type, 1000059006);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkPhysicalDeviceMemoryBudgetPropertiesEXT)]
+pub struct PhysicalDeviceMemoryBudgetPropertiesEXT<Next = Null> {
+    pub stype: PhysicalDeviceMemoryBudgetPropertiesEXTType,
+    pub next: Next,
+    pub heap_budget: [u64; 16],
+    pub heap_usage: [u64; 16],
+}
+structure_type!(PhysicalDeviceMemoryBudgetPropertiesEXTType, 1000237000);
+
 #[repr(C)]
 #[derive(Debug, Default)]
 #[doc = crate::man_link!(VkMemoryRequirements)]
@@ -435,6 +513,55 @@ pub struct MemoryRequirements {
     pub memory_type_bits: u32,
 }
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkMemoryRequirements2)]
+pub struct MemoryRequirements2<Next = Null> {
+    pub stype: MemoryRequirements2Type,
+    pub next: Next,
+    pub memory_requirements: MemoryRequirements,
+}
+structure_type!(MemoryRequirements2Type, 1000146003);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkMemoryDedicatedRequirements)]
+pub struct MemoryDedicatedRequirements<Next = Null> {
+    pub stype: MemoryDedicatedRequirementsType,
+    pub next: Next,
+    pub prefers_dedicated_allocation: Bool,
+    pub requires_dedicated_allocation: Bool,
+}
+structure_type!(MemoryDedicatedRequirementsType, 1000127000);
+
+impl<Next> MemoryDedicatedRequirements<Next> {
+    /// Returns true if the resource should get its own allocation.
+    pub fn dedicated(&self) -> bool {
+        self.prefers_dedicated_allocation.as_bool()
+            || self.requires_dedicated_allocation.as_bool()
+    }
+}
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkBufferMemoryRequirementsInfo2)]
+pub struct BufferMemoryRequirementsInfo2<'a, Next = Null> {
+    pub stype: BufferMemoryRequirementsInfo2Type,
+    pub next: Next,
+    pub buffer: Ref<'a, VkBuffer>,
+}
+structure_type!(BufferMemoryRequirementsInfo2Type, 1000146000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkImageMemoryRequirementsInfo2)]
+pub struct ImageMemoryRequirementsInfo2<'a, Next = Null> {
+    pub stype: ImageMemoryRequirementsInfo2Type,
+    pub next: Next,
+    pub image: Ref<'a, VkImage>,
+}
+structure_type!(ImageMemoryRequirementsInfo2Type, 1000146001);
+
 impl MemoryRequirements {
     pub(crate) fn clear_host_visible_types(
         &mut self, props: &PhysicalDeviceMemoryProperties,
@@ -508,6 +635,18 @@ pub struct ExtensionProperties {
 
 pub const MAX_EXTENSION_NAME_SIZE: usize = 256;
 
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkLayerProperties)]
+pub struct LayerProperties {
+    pub layer_name: CharArray<MAX_EXTENSION_NAME_SIZE>,
+    pub spec_version: u32,
+    pub implementation_version: u32,
+    pub description: CharArray<MAX_DESCRIPTION_SIZE>,
+}
+
+pub const MAX_DESCRIPTION_SIZE: usize = 256;
+
 #[repr(C)]
 #[derive(Debug)]
 #[doc = crate::man_link!(VkPhysicalDeviceProperties)]
@@ -525,6 +664,26 @@ pub struct PhysicalDeviceProperties {
 
 pub const MAX_PHYSICAL_DEVICE_NAME_SIZE: usize = 256;
 
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceProperties2)]
+pub struct PhysicalDeviceProperties2<Next = Null> {
+    pub stype: PhysicalDeviceProperties2Type,
+    pub next: Next,
+    pub properties: PhysicalDeviceProperties,
+}
+structure_type!(PhysicalDeviceProperties2Type, 1000059001);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkPhysicalDeviceExternalMemoryHostPropertiesEXT)]
+pub struct PhysicalDeviceExternalMemoryHostPropertiesEXT<Next = Null> {
+    pub stype: PhysicalDeviceExternalMemoryHostPropertiesEXTType,
+    pub next: Next,
+    pub min_imported_host_pointer_alignment: u64,
+}
+structure_type!(PhysicalDeviceExternalMemoryHostPropertiesEXTType, 1000178002);
+
 #[repr(C)]
 #[derive(Debug)]
 #[doc = crate::man_link!(VkPhysicalDeviceLimits)]
@@ -745,6 +904,362 @@ pub struct PhysicalDeviceFeatures {
     pub inherited_queries: Bool,
 }
 
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceFeatures2)]
+pub struct PhysicalDeviceFeatures2<Next = Null> {
+    pub stype: PhysicalDeviceFeatures2Type,
+    pub next: Next,
+    pub features: PhysicalDeviceFeatures,
+}
+structure_type!(PhysicalDeviceFeatures2Type, 1000059000);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVulkan11Features)]
+pub struct PhysicalDeviceVulkan11Features<Next = Null> {
+    pub stype: PhysicalDeviceVulkan11FeaturesType,
+    pub next: Next,
+    pub storage_buffer16_bit_access: Bool,
+    pub uniform_and_storage_buffer16_bit_access: Bool,
+    pub storage_push_constant16: Bool,
+    pub storage_input_output16: Bool,
+    pub multiview: Bool,
+    pub multiview_geometry_shader: Bool,
+    pub multiview_tessellation_shader: Bool,
+    pub variable_pointers_storage_buffer: Bool,
+    pub variable_pointers: Bool,
+    pub protected_memory: Bool,
+    pub sampler_ycbcr_conversion: Bool,
+    pub shader_draw_parameters: Bool,
+}
+structure_type!(PhysicalDeviceVulkan11FeaturesType, 49);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVulkan12Features)]
+pub struct PhysicalDeviceVulkan12Features<Next = Null> {
+    pub stype: PhysicalDeviceVulkan12FeaturesType,
+    pub next: Next,
+    pub sampler_mirror_clamp_to_edge: Bool,
+    pub draw_indirect_count: Bool,
+    pub storage_buffer8_bit_access: Bool,
+    pub uniform_and_storage_buffer8_bit_access: Bool,
+    pub storage_push_constant8: Bool,
+    pub shader_buffer_int64_atomics: Bool,
+    pub shader_shared_int64_atomics: Bool,
+    pub shader_float16: Bool,
+    pub shader_int8: Bool,
+    pub descriptor_indexing: Bool,
+    pub shader_input_attachment_array_dynamic_indexing: Bool,
+    pub shader_uniform_texel_buffer_array_dynamic_indexing: Bool,
+    pub shader_storage_texel_buffer_array_dynamic_indexing: Bool,
+    pub shader_uniform_buffer_array_non_uniform_indexing: Bool,
+    pub shader_sampled_image_array_non_uniform_indexing: Bool,
+    pub shader_storage_buffer_array_non_uniform_indexing: Bool,
+    pub shader_storage_image_array_non_uniform_indexing: Bool,
+    pub shader_input_attachment_array_non_uniform_indexing: Bool,
+    pub shader_uniform_texel_buffer_array_non_uniform_indexing: Bool,
+    pub shader_storage_texel_buffer_array_non_uniform_indexing: Bool,
+    pub descriptor_binding_uniform_buffer_update_after_bind: Bool,
+    pub descriptor_binding_sampled_image_update_after_bind: Bool,
+    pub descriptor_binding_storage_image_update_after_bind: Bool,
+    pub descriptor_binding_storage_buffer_update_after_bind: Bool,
+    pub descriptor_binding_uniform_texel_buffer_update_after_bind: Bool,
+    pub descriptor_binding_storage_texel_buffer_update_after_bind: Bool,
+    pub descriptor_binding_update_unused_while_pending: Bool,
+    pub descriptor_binding_partially_bound: Bool,
+    pub descriptor_binding_variable_descriptor_count: Bool,
+    pub runtime_descriptor_array: Bool,
+    pub sampler_filter_minmax: Bool,
+    pub scalar_block_layout: Bool,
+    pub imageless_framebuffer: Bool,
+    pub uniform_buffer_standard_layout: Bool,
+    pub shader_subgroup_extended_types: Bool,
+    pub separate_depth_stencil_layouts: Bool,
+    pub host_query_reset: Bool,
+    pub timeline_semaphore: Bool,
+    pub buffer_device_address: Bool,
+    pub buffer_device_address_capture_replay: Bool,
+    pub buffer_device_address_multi_device: Bool,
+    pub vulkan_memory_model: Bool,
+    pub vulkan_memory_model_device_scope: Bool,
+    pub vulkan_memory_model_availability_visibility_chains: Bool,
+    pub shader_output_viewport_index: Bool,
+    pub shader_output_layer: Bool,
+    pub subgroup_broadcast_dynamic_id: Bool,
+}
+structure_type!(PhysicalDeviceVulkan12FeaturesType, 51);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVulkan13Features)]
+pub struct PhysicalDeviceVulkan13Features<Next = Null> {
+    pub stype: PhysicalDeviceVulkan13FeaturesType,
+    pub next: Next,
+    pub robust_image_access: Bool,
+    pub inline_uniform_block: Bool,
+    pub descriptor_binding_inline_uniform_block_update_after_bind: Bool,
+    pub pipeline_creation_cache_control: Bool,
+    pub private_data: Bool,
+    pub shader_demote_to_helper_invocation: Bool,
+    pub shader_terminate_invocation: Bool,
+    pub subgroup_size_control: Bool,
+    pub compute_full_subgroups: Bool,
+    pub synchronization2: Bool,
+    pub texture_compression_astc_hdr: Bool,
+    pub shader_zero_initialize_workgroup_memory: Bool,
+    pub dynamic_rendering: Bool,
+    pub shader_integer_dot_product: Bool,
+    pub maintenance4: Bool,
+}
+structure_type!(PhysicalDeviceVulkan13FeaturesType, 53);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDevice16BitStorageFeatures)]
+pub struct PhysicalDevice16BitStorageFeatures<Next = Null> {
+    pub stype: PhysicalDevice16BitStorageFeaturesType,
+    pub next: Next,
+    pub storage_buffer16_bit_access: Bool,
+    pub uniform_and_storage_buffer16_bit_access: Bool,
+    pub storage_push_constant16: Bool,
+    pub storage_input_output16: Bool,
+}
+structure_type!(PhysicalDevice16BitStorageFeaturesType, 1000083000);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceMultiviewFeatures)]
+pub struct PhysicalDeviceMultiviewFeatures<Next = Null> {
+    pub stype: PhysicalDeviceMultiviewFeaturesType,
+    pub next: Next,
+    pub multiview: Bool,
+    pub multiview_geometry_shader: Bool,
+    pub multiview_tessellation_shader: Bool,
+}
+structure_type!(PhysicalDeviceMultiviewFeaturesType, 1000053001);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVariablePointersFeatures)]
+pub struct PhysicalDeviceVariablePointersFeatures<Next = Null> {
+    pub stype: PhysicalDeviceVariablePointersFeaturesType,
+    pub next: Next,
+    pub variable_pointers_storage_buffer: Bool,
+    pub variable_pointers: Bool,
+}
+structure_type!(PhysicalDeviceVariablePointersFeaturesType, 1000120000);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceProtectedMemoryFeatures)]
+pub struct PhysicalDeviceProtectedMemoryFeatures<Next = Null> {
+    pub stype: PhysicalDeviceProtectedMemoryFeaturesType,
+    pub next: Next,
+    pub protected_memory: Bool,
+}
+structure_type!(PhysicalDeviceProtectedMemoryFeaturesType, 1000145001);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceSamplerYcbcrConversionFeatures)]
+pub struct PhysicalDeviceSamplerYcbcrConversionFeatures<Next = Null> {
+    pub stype: PhysicalDeviceSamplerYcbcrConversionFeaturesType,
+    pub next: Next,
+    pub sampler_ycbcr_conversion: Bool,
+}
+structure_type!(PhysicalDeviceSamplerYcbcrConversionFeaturesType, 1000156004);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceShaderDrawParametersFeatures)]
+pub struct PhysicalDeviceShaderDrawParametersFeatures<Next = Null> {
+    pub stype: PhysicalDeviceShaderDrawParametersFeaturesType,
+    pub next: Next,
+    pub shader_draw_parameters: Bool,
+}
+structure_type!(PhysicalDeviceShaderDrawParametersFeaturesType, 1000063000);
+
+#[repr(C)]
+#[derive(Clone, Default, Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceMemoryPriorityFeaturesEXT)]
+pub struct PhysicalDeviceMemoryPriorityFeaturesEXT<Next = Null> {
+    pub stype: PhysicalDeviceMemoryPriorityFeaturesEXTType,
+    pub next: Next,
+    pub memory_priority: Bool,
+}
+structure_type!(PhysicalDeviceMemoryPriorityFeaturesEXTType, 1000238000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVulkan11Properties)]
+pub struct PhysicalDeviceVulkan11Properties<Next = Null> {
+    pub stype: PhysicalDeviceVulkan11PropertiesType,
+    pub next: Next,
+    pub device_uuid: UUID,
+    pub driver_uuid: UUID,
+    pub device_luid: [u8; LUID_SIZE],
+    pub device_node_mask: u32,
+    pub device_luid_valid: Bool,
+    pub subgroup_size: u32,
+    pub subgroup_supported_stages: ShaderStageFlags,
+    pub subgroup_supported_operations: SubgroupFeatureFlags,
+    pub subgroup_quad_operations_in_all_stages: Bool,
+    pub point_clipping_behavior: PointClippingBehavior,
+    pub max_multiview_view_count: u32,
+    pub max_multiview_instance_index: u32,
+    pub protected_no_fault: Bool,
+    pub max_per_set_descriptors: u32,
+    pub max_memory_allocation_size: u64,
+}
+structure_type!(PhysicalDeviceVulkan11PropertiesType, 50);
+
+pub const LUID_SIZE: usize = 8;
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVulkan12Properties)]
+pub struct PhysicalDeviceVulkan12Properties<Next = Null> {
+    pub stype: PhysicalDeviceVulkan12PropertiesType,
+    pub next: Next,
+    pub driver_id: DriverId,
+    pub driver_name: CharArray<MAX_DRIVER_NAME_SIZE>,
+    pub driver_info: CharArray<MAX_DRIVER_INFO_SIZE>,
+    pub conformance_version: ConformanceVersion,
+    pub denorm_behavior_independence: ShaderFloatControlsIndependence,
+    pub rounding_mode_independence: ShaderFloatControlsIndependence,
+    pub shader_signed_zero_inf_nan_preserve_float16: Bool,
+    pub shader_signed_zero_inf_nan_preserve_float32: Bool,
+    pub shader_signed_zero_inf_nan_preserve_float64: Bool,
+    pub shader_denorm_preserve_float16: Bool,
+    pub shader_denorm_preserve_float32: Bool,
+    pub shader_denorm_preserve_float64: Bool,
+    pub shader_denorm_flush_to_zero_float16: Bool,
+    pub shader_denorm_flush_to_zero_float32: Bool,
+    pub shader_denorm_flush_to_zero_float64: Bool,
+    pub shader_rounding_mode_rte_float16: Bool,
+    pub shader_rounding_mode_rte_float32: Bool,
+    pub shader_rounding_mode_rte_float64: Bool,
+    pub shader_rounding_mode_rtz_float16: Bool,
+    pub shader_rounding_mode_rtz_float32: Bool,
+    pub shader_rounding_mode_rtz_float64: Bool,
+    pub max_update_after_bind_descriptors_in_all_pools: u32,
+    pub shader_uniform_buffer_array_non_uniform_indexing_native: Bool,
+    pub shader_sampled_image_array_non_uniform_indexing_native: Bool,
+    pub shader_storage_buffer_array_non_uniform_indexing_native: Bool,
+    pub shader_storage_image_array_non_uniform_indexing_native: Bool,
+    pub shader_input_attachment_array_non_uniform_indexing_native: Bool,
+    pub robust_buffer_access_update_after_bind: Bool,
+    pub quad_divergent_implicit_lod: Bool,
+    pub max_per_stage_descriptor_update_after_bind_samplers: u32,
+    pub max_per_stage_descriptor_update_after_bind_uniform_buffers: u32,
+    pub max_per_stage_descriptor_update_after_bind_storage_buffers: u32,
+    pub max_per_stage_descriptor_update_after_bind_sampled_images: u32,
+    pub max_per_stage_descriptor_update_after_bind_storage_images: u32,
+    pub max_per_stage_descriptor_update_after_bind_input_attachments: u32,
+    pub max_per_stage_update_after_bind_resources: u32,
+    pub max_descriptor_set_update_after_bind_samplers: u32,
+    pub max_descriptor_set_update_after_bind_uniform_buffers: u32,
+    pub max_descriptor_set_update_after_bind_uniform_buffers_dynamic: u32,
+    pub max_descriptor_set_update_after_bind_storage_buffers: u32,
+    pub max_descriptor_set_update_after_bind_storage_buffers_dynamic: u32,
+    pub max_descriptor_set_update_after_bind_sampled_images: u32,
+    pub max_descriptor_set_update_after_bind_storage_images: u32,
+    pub max_descriptor_set_update_after_bind_input_attachments: u32,
+    pub supported_depth_resolve_modes: ResolveModeFlags,
+    pub supported_stencil_resolve_modes: ResolveModeFlags,
+    pub independent_resolve_none: Bool,
+    pub independent_resolve: Bool,
+    pub filter_minmax_single_component_formats: Bool,
+    pub filter_minmax_image_component_mapping: Bool,
+    pub max_timeline_semaphore_value_difference: u64,
+    pub framebuffer_integer_color_sample_counts: SampleCountFlags,
+}
+structure_type!(PhysicalDeviceVulkan12PropertiesType, 52);
+
+pub const MAX_DRIVER_NAME_SIZE: usize = 256;
+pub const MAX_DRIVER_INFO_SIZE: usize = 256;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[doc = crate::man_link!(VkConformanceVersion)]
+pub struct ConformanceVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub subminor: u8,
+    pub patch: u8,
+}
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkPhysicalDeviceVulkan13Properties)]
+pub struct PhysicalDeviceVulkan13Properties<Next = Null> {
+    pub stype: PhysicalDeviceVulkan13PropertiesType,
+    pub next: Next,
+    pub min_subgroup_size: u32,
+    pub max_subgroup_size: u32,
+    pub max_compute_workgroup_subgroups: u32,
+    pub required_subgroup_size_stages: ShaderStageFlags,
+    pub max_inline_uniform_block_size: u32,
+    pub max_per_stage_descriptor_inline_uniform_blocks: u32,
+    pub max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: u32,
+    pub max_descriptor_set_inline_uniform_blocks: u32,
+    pub max_descriptor_set_update_after_bind_inline_uniform_blocks: u32,
+    pub max_inline_uniform_total_size: u32,
+    pub integer_dot_product8_bit_unsigned_accelerated: Bool,
+    pub integer_dot_product8_bit_signed_accelerated: Bool,
+    pub integer_dot_product8_bit_mixed_signedness_accelerated: Bool,
+    pub integer_dot_product4x8_bit_packed_unsigned_accelerated: Bool,
+    pub integer_dot_product4x8_bit_packed_signed_accelerated: Bool,
+    pub integer_dot_product4x8_bit_packed_mixed_signedness_accelerated: Bool,
+    pub integer_dot_product16_bit_unsigned_accelerated: Bool,
+    pub integer_dot_product16_bit_signed_accelerated: Bool,
+    pub integer_dot_product16_bit_mixed_signedness_accelerated: Bool,
+    pub integer_dot_product32_bit_unsigned_accelerated: Bool,
+    pub integer_dot_product32_bit_signed_accelerated: Bool,
+    pub integer_dot_product32_bit_mixed_signedness_accelerated: Bool,
+    pub integer_dot_product64_bit_unsigned_accelerated: Bool,
+    pub integer_dot_product64_bit_signed_accelerated: Bool,
+    pub integer_dot_product64_bit_mixed_signedness_accelerated: Bool,
+    pub integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating8_bit_signed_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating4x8_bit_packed_mixed_signedness_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating16_bit_signed_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating32_bit_signed_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating64_bit_signed_accelerated:
+        Bool,
+    pub integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated:
+        Bool,
+    pub storage_texel_buffer_offset_alignment_bytes: u64,
+    pub storage_texel_buffer_offset_single_texel_alignment: Bool,
+    pub uniform_texel_buffer_offset_alignment_bytes: u64,
+    pub uniform_texel_buffer_offset_single_texel_alignment: Bool,
+    pub max_buffer_size: u64,
+}
+structure_type!(PhysicalDeviceVulkan13PropertiesType, 54);
+
 #[repr(C)]
 #[derive(Debug, Default)]
 #[doc = crate::man_link!(VkSubmitInfo)]
@@ -770,6 +1285,92 @@ pub struct MemoryAllocateInfo<Next = Null> {
 }
 structure_type!(MemoryAllocateInfoType, 5);
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkMemoryDedicatedAllocateInfo)]
+pub struct MemoryDedicatedAllocateInfo<'a, Next = Null> {
+    pub stype: MemoryDedicatedAllocateInfoType,
+    pub next: Next,
+    pub image: Option<Ref<'a, VkImage>>,
+    pub buffer: Option<Ref<'a, VkBuffer>>,
+}
+structure_type!(MemoryDedicatedAllocateInfoType, 1000127001);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkMemoryPriorityAllocateInfoEXT)]
+pub struct MemoryPriorityAllocateInfoEXT<Next = Null> {
+    pub stype: MemoryPriorityAllocateInfoEXTType,
+    pub next: Next,
+    pub priority: f32,
+}
+structure_type!(MemoryPriorityAllocateInfoEXTType, 1000238001);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkExportMemoryAllocateInfo)]
+pub struct ExportMemoryAllocateInfo<Next = Null> {
+    pub stype: ExportMemoryAllocateInfoType,
+    pub next: Next,
+    pub handle_types: ExternalMemoryHandleTypeFlags,
+}
+structure_type!(ExportMemoryAllocateInfoType, 1000072002);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkImportMemoryFdInfoKHR)]
+pub struct ImportMemoryFdInfoKHR<Next = Null> {
+    pub stype: ImportMemoryFdInfoKHRType,
+    pub next: Next,
+    pub handle_type: ExternalMemoryHandleTypeFlags,
+    pub fd: std::os::raw::c_int,
+}
+structure_type!(ImportMemoryFdInfoKHRType, 1000074000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkMemoryGetFdInfoKHR)]
+pub struct MemoryGetFdInfoKHR<'a, Next = Null> {
+    pub stype: MemoryGetFdInfoKHRType,
+    pub next: Next,
+    pub memory: Ref<'a, VkDeviceMemory>,
+    pub handle_type: ExternalMemoryHandleTypeFlags,
+}
+structure_type!(MemoryGetFdInfoKHRType, 1000074002);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkImportMemoryHostPointerInfoEXT)]
+pub struct ImportMemoryHostPointerInfoEXT<Next = Null> {
+    pub stype: ImportMemoryHostPointerInfoEXTType,
+    pub next: Next,
+    pub handle_type: ExternalMemoryHandleTypeFlags,
+    pub host_pointer: NonNull<c_void>,
+}
+structure_type!(ImportMemoryHostPointerInfoEXTType, 1000178000);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkMemoryHostPointerPropertiesEXT)]
+pub struct MemoryHostPointerPropertiesEXT<Next = Null> {
+    pub stype: MemoryHostPointerPropertiesEXTType,
+    pub next: Next,
+    pub memory_type_bits: u32,
+}
+structure_type!(MemoryHostPointerPropertiesEXTType, 1000178001);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkMappedMemoryRange)]
+pub struct MappedMemoryRange<'a, Next = Null> {
+    pub stype: MappedMemoryRangeType,
+    pub next: Next,
+    pub memory: Ref<'a, VkDeviceMemory>,
+    pub offset: u64,
+    pub size: u64,
+}
+structure_type!(MappedMemoryRangeType, 6);
+
 #[repr(C)]
 #[derive(Debug, Default)]
 #[doc = crate::man_link!(VkFenceCreateInfo)]
@@ -780,6 +1381,40 @@ pub struct FenceCreateInfo<Next = Null> {
 }
 structure_type!(FenceCreateInfoType, 8);
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkExportFenceCreateInfo)]
+pub struct ExportFenceCreateInfo<Next = Null> {
+    pub stype: ExportFenceCreateInfoType,
+    pub next: Next,
+    pub handle_types: ExternalFenceHandleTypeFlags,
+}
+structure_type!(ExportFenceCreateInfoType, 1000113000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkImportFenceFdInfoKHR)]
+pub struct ImportFenceFdInfoKHR<'a, Next = Null> {
+    pub stype: ImportFenceFdInfoKHRType,
+    pub next: Next,
+    pub fence: Mut<'a, VkFence>,
+    pub flags: FenceImportFlags,
+    pub handle_type: ExternalFenceHandleTypeFlags,
+    pub fd: std::os::raw::c_int,
+}
+structure_type!(ImportFenceFdInfoKHRType, 1000115000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkFenceGetFdInfoKHR)]
+pub struct FenceGetFdInfoKHR<'a, Next = Null> {
+    pub stype: FenceGetFdInfoKHRType,
+    pub next: Next,
+    pub fence: Ref<'a, VkFence>,
+    pub handle_type: ExternalFenceHandleTypeFlags,
+}
+structure_type!(FenceGetFdInfoKHRType, 1000115001);
+
 #[repr(C)]
 #[derive(Debug, Default)]
 #[doc = crate::man_link!(VkSemaphoreCreateInfo)]
@@ -790,6 +1425,96 @@ pub struct SemaphoreCreateInfo<Next = Null> {
 }
 structure_type!(SemaphoreCreateInfoType, 9);
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkExportSemaphoreCreateInfo)]
+pub struct ExportSemaphoreCreateInfo<Next = Null> {
+    pub stype: ExportSemaphoreCreateInfoType,
+    pub next: Next,
+    pub handle_types: ExternalSemaphoreHandleTypeFlags,
+}
+structure_type!(ExportSemaphoreCreateInfoType, 1000077000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkImportSemaphoreFdInfoKHR)]
+pub struct ImportSemaphoreFdInfoKHR<'a, Next = Null> {
+    pub stype: ImportSemaphoreFdInfoKHRType,
+    pub next: Next,
+    pub semaphore: Mut<'a, VkSemaphore>,
+    pub flags: SemaphoreImportFlags,
+    pub handle_type: ExternalSemaphoreHandleTypeFlags,
+    pub fd: std::os::raw::c_int,
+}
+structure_type!(ImportSemaphoreFdInfoKHRType, 1000079000);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkSemaphoreGetFdInfoKHR)]
+pub struct SemaphoreGetFdInfoKHR<'a, Next = Null> {
+    pub stype: SemaphoreGetFdInfoKHRType,
+    pub next: Next,
+    pub semaphore: Ref<'a, VkSemaphore>,
+    pub handle_type: ExternalSemaphoreHandleTypeFlags,
+}
+structure_type!(SemaphoreGetFdInfoKHRType, 1000079001);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkSemaphoreTypeCreateInfo)]
+pub struct SemaphoreTypeCreateInfo<Next = Null> {
+    pub stype: SemaphoreTypeCreateInfoType,
+    pub next: Next,
+    pub semaphore_type: SemaphoreType,
+    pub initial_value: u64,
+}
+structure_type!(SemaphoreTypeCreateInfoType, 1000207002);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkTimelineSemaphoreSubmitInfo)]
+pub struct TimelineSemaphoreSubmitInfo<'a, Next = Null> {
+    pub stype: TimelineSemaphoreSubmitInfoType,
+    pub next: Next,
+    pub wait_semaphore_values: Slice<'a, u64>,
+    pub signal_semaphore_values: Slice<'a, u64>,
+}
+structure_type!(TimelineSemaphoreSubmitInfoType, 1000207003);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkSemaphoreWaitInfo)]
+pub struct SemaphoreWaitInfo<'a, Next = Null> {
+    pub stype: SemaphoreWaitInfoType,
+    pub next: Next,
+    pub flags: SemaphoreWaitFlags,
+    pub semaphores: Slice_<'a, Ref<'a, VkSemaphore>>,
+    // Safety: Must be same length as semaphores
+    pub values: Array<'a, u64>,
+}
+structure_type!(SemaphoreWaitInfoType, 1000207004);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkSemaphoreSignalInfo)]
+pub struct SemaphoreSignalInfo<'a, Next = Null> {
+    pub stype: SemaphoreSignalInfoType,
+    pub next: Next,
+    pub semaphore: Ref<'a, VkSemaphore>,
+    pub value: u64,
+}
+structure_type!(SemaphoreSignalInfoType, 1000207005);
+
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkEventCreateInfo)]
+pub struct EventCreateInfo<Next = Null> {
+    pub stype: EventCreateInfoType,
+    pub next: Next,
+    pub flags: EventCreateFlags,
+}
+structure_type!(EventCreateInfoType, 10);
+
 #[repr(C)]
 #[derive(Debug, Default)]
 #[doc = crate::man_link!(VkBufferCreateInfo)]
@@ -804,6 +1529,47 @@ pub struct BufferCreateInfo<'a, Next = Null> {
 }
 structure_type!(BufferCreateInfoType, 12);
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkExternalMemoryBufferCreateInfo)]
+pub struct ExternalMemoryBufferCreateInfo<Next = Null> {
+    pub stype: ExternalMemoryBufferCreateInfoType,
+    pub next: Next,
+    pub handle_types: ExternalMemoryHandleTypeFlags,
+}
+structure_type!(ExternalMemoryBufferCreateInfoType, 1000072000);
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+#[doc = crate::man_link!(VkFormatProperties)]
+pub struct FormatProperties {
+    pub linear_tiling_features: FormatFeatureFlags,
+    pub optimal_tiling_features: FormatFeatureFlags,
+    pub buffer_features: FormatFeatureFlags,
+}
+
+impl FormatProperties {
+    /// Returns the features supported for images with the given tiling.
+    pub fn tiling_features(&self, tiling: ImageTiling) -> FormatFeatureFlags {
+        if tiling == ImageTiling::LINEAR {
+            self.linear_tiling_features
+        } else {
+            self.optimal_tiling_features
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+#[doc = crate::man_link!(VkImageFormatProperties)]
+pub struct ImageFormatProperties {
+    pub max_extent: Extent3D,
+    pub max_mip_levels: u32,
+    pub max_array_layers: u32,
+    pub sample_counts: SampleCountFlags,
+    pub max_resource_size: u64,
+}
+
 #[repr(C)]
 #[derive(Debug)]
 #[doc = crate::man_link!(VkImageCreateInfo)]
@@ -825,6 +1591,16 @@ pub struct ImageCreateInfo<'a, Next = Null> {
 }
 structure_type!(ImageCreateInfoType, 14);
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkExternalMemoryImageCreateInfo)]
+pub struct ExternalMemoryImageCreateInfo<Next = Null> {
+    pub stype: ExternalMemoryImageCreateInfoType,
+    pub next: Next,
+    pub handle_types: ExternalMemoryHandleTypeFlags,
+}
+structure_type!(ExternalMemoryImageCreateInfoType, 1000072001);
+
 impl<'a> Default for ImageCreateInfo<'a> {
     fn default() -> Self {
         Self {
@@ -1562,6 +2338,19 @@ pub struct CommandPoolCreateInfo<Next = Null> {
 }
 structure_type!(CommandPoolCreateInfoType, 39);
 
+#[repr(C)]
+#[derive(Debug, Default)]
+#[doc = crate::man_link!(VkQueryPoolCreateInfo)]
+pub struct QueryPoolCreateInfo<Next = Null> {
+    pub stype: QueryPoolCreateInfoType,
+    pub next: Next,
+    pub flags: QueryPoolCreateFlags,
+    pub query_type: QueryType,
+    pub query_count: u32,
+    pub pipeline_statistics: QueryPipelineStatisticFlags,
+}
+structure_type!(QueryPoolCreateInfoType, 11);
+
 #[repr(C)]
 #[derive(Debug)]
 #[doc = crate::man_link!(VkCommandBufferAllocateInfo)]
@@ -1667,6 +2456,78 @@ pub struct MetalSurfaceCreateInfoEXT<Next = Null> {
 }
 structure_type!(MetalSurfaceCreateInfoEXTType, 1000217000);
 
+/// Create with [`DebugUtilsMessengerCreateInfoEXT::new`]. Can be chained onto
+/// [`InstanceCreateInfo`] with [`ExtensionChain::new`] to receive messages
+/// from instance creation and destruction, in which case the instance keeps
+/// the callback alive.
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkDebugUtilsMessengerCreateInfoEXT)]
+pub struct DebugUtilsMessengerCreateInfoEXT {
+    pub(crate) stype: DebugUtilsMessengerCreateInfoEXTType,
+    pub(crate) next: Null,
+    pub(crate) flags: DebugUtilsMessengerCreateFlagsEXT,
+    pub(crate) message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    pub(crate) message_type: DebugUtilsMessageTypeFlagsEXT,
+    pub(crate) pfn_user_callback: DebugUtilsMessengerCallbackEXT,
+    pub(crate) user_data: NonNull<crate::ext::DebugCallback>,
+    /// Not part of the Vulkan structure. Owns `user_data`.
+    pub(crate) callback: Arc<crate::ext::DebugCallback>,
+}
+structure_type!(DebugUtilsMessengerCreateInfoEXTType, 1000128004);
+
+unsafe impl Extends<InstanceCreateInfo<'static>>
+    for DebugUtilsMessengerCreateInfoEXT
+{
+}
+
+pub(crate) type DebugUtilsMessengerCallbackEXT =
+    unsafe extern "system" fn(
+        DebugUtilsMessageSeverityFlagsEXT,
+        DebugUtilsMessageTypeFlagsEXT,
+        &DebugUtilsMessengerCallbackDataEXT,
+        &crate::ext::DebugCallback,
+    ) -> Bool;
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkDebugUtilsMessengerCallbackDataEXT)]
+pub struct DebugUtilsMessengerCallbackDataEXT<'a> {
+    pub stype: DebugUtilsMessengerCallbackDataEXTType,
+    pub next: Option<NonNull<c_void>>,
+    pub flags: DebugUtilsMessengerCallbackDataFlagsEXT,
+    pub message_id_name: Option<Str<'a>>,
+    pub message_id_number: i32,
+    pub message: Option<Str<'a>>,
+    pub queue_labels: Slice<'a, DebugUtilsLabelEXT<'a>>,
+    pub cmd_buf_labels: Slice<'a, DebugUtilsLabelEXT<'a>>,
+    pub objects: Slice<'a, DebugUtilsObjectNameInfoEXT<'a>>,
+}
+structure_type!(DebugUtilsMessengerCallbackDataEXTType, 1000128003);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkDebugUtilsLabelEXT)]
+pub struct DebugUtilsLabelEXT<'a> {
+    pub stype: DebugUtilsLabelEXTType,
+    pub next: Option<NonNull<c_void>>,
+    pub label_name: Str<'a>,
+    pub color: [f32; 4],
+}
+structure_type!(DebugUtilsLabelEXTType, 1000128002);
+
+#[repr(C)]
+#[derive(Debug)]
+#[doc = crate::man_link!(VkDebugUtilsObjectNameInfoEXT)]
+pub struct DebugUtilsObjectNameInfoEXT<'a> {
+    pub stype: DebugUtilsObjectNameInfoEXTType,
+    pub next: Option<NonNull<c_void>>,
+    pub object_type: ObjectType,
+    pub object_handle: u64,
+    pub object_name: Option<Str<'a>>,
+}
+structure_type!(DebugUtilsObjectNameInfoEXTType, 1000128000);
+
 #[repr(C)]
 #[derive(Debug)]
 #[doc = crate::man_link!(VkXlibSurfaceCreateInfoKHR)]